@@ -0,0 +1,192 @@
+use crate::days::Day;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Differences smaller than this are timer noise, not regressions.
+const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+/// Median timings of parsing and solving one day, in nanoseconds.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timings {
+    pub day: u8,
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    /// `None` for days without a second puzzle.
+    pub part2_ns: Option<u64>,
+}
+
+/// Results of a benchmark run, as written to and read from JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Report {
+    /// Number of runs each timing is the median of.
+    pub runs: usize,
+    pub days: Vec<Timings>,
+}
+
+impl Report {
+    pub fn load(path: &Path) -> Result<Report, Box<dyn Error>> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        Ok(serde_json::from_str(&json).map_err(|e| format!("{}: {}", path.display(), e))?)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        std::fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(())
+    }
+}
+
+fn median(mut samples: Vec<Duration>) -> u64 {
+    samples.sort();
+    samples[samples.len() / 2].as_nanos() as u64
+}
+
+/// Times parsing `input` and solving both parts `runs` times.
+pub fn time_day(day: &Day, input: &str, runs: usize) -> Result<Timings, Box<dyn Error>> {
+    let mut parse = Vec::with_capacity(runs);
+    let mut part1 = Vec::with_capacity(runs);
+    let mut part2 = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        let parsed = (day.parse)(input)?;
+        parse.push(start.elapsed());
+
+        let start = Instant::now();
+        parsed.part1();
+        part1.push(start.elapsed());
+
+        let start = Instant::now();
+        if parsed.part2().is_some() {
+            part2.push(start.elapsed());
+        }
+    }
+    Ok(Timings {
+        day: day.number,
+        parse_ns: median(parse),
+        part1_ns: median(part1),
+        part2_ns: (!part2.is_empty()).then(|| median(part2)),
+    })
+}
+
+/// A timing that got slower than its baseline by more than the allowed threshold.
+pub struct Regression {
+    pub day: u8,
+    pub stage: &'static str,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Regression {
+    pub fn percent(&self) -> f64 {
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1.0) * 100.0
+    }
+}
+
+fn stages(timings: &Timings) -> [(&'static str, Option<u64>); 3] {
+    [
+        ("parse", Some(timings.parse_ns)),
+        ("part1", Some(timings.part1_ns)),
+        ("part2", timings.part2_ns),
+    ]
+}
+
+/// Timings of `current` that are more than `threshold` percent slower than in `baseline`.
+/// Days or parts missing from the baseline are not compared.
+pub fn compare(baseline: &Report, current: &Report, threshold: f64) -> Vec<Regression> {
+    let mut regressions = Vec::new();
+    for timings in &current.days {
+        let Some(old) = baseline.days.iter().find(|old| old.day == timings.day) else {
+            continue;
+        };
+        for ((stage, new), (_, old)) in stages(timings).into_iter().zip(stages(old)) {
+            let (Some(new), Some(old)) = (new, old) else {
+                continue;
+            };
+            let (new, old) = (Duration::from_nanos(new), Duration::from_nanos(old));
+            let regression = Regression {
+                day: timings.day,
+                stage,
+                baseline: old,
+                current: new,
+            };
+            if new > old + NOISE_FLOOR && regression.percent() > threshold {
+                regressions.push(regression);
+            }
+        }
+    }
+    regressions
+}
+
+/// Human-readable duration with a unit that fits its magnitude.
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.2}s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.2}ms", secs * 1e3)
+    } else {
+        format!("{:.2}µs", secs * 1e6)
+    }
+}
+
+/// One line per day with the timing of each stage.
+pub fn print_timings(timings: &Timings) {
+    let column = |ns: Option<u64>| {
+        ns.map_or("-".to_string(), |ns| {
+            format_duration(Duration::from_nanos(ns))
+        })
+    };
+    println!(
+        "Day {:>2}  parse {:>10}  part1 {:>10}  part2 {:>10}",
+        timings.day,
+        column(Some(timings.parse_ns)),
+        column(Some(timings.part1_ns)),
+        column(timings.part2_ns),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(part1_ns: u64, part2_ns: Option<u64>) -> Report {
+        Report {
+            runs: 1,
+            days: vec![Timings {
+                day: 7,
+                parse_ns: 1_000,
+                part1_ns,
+                part2_ns,
+            }],
+        }
+    }
+
+    #[test]
+    fn flags_slower_parts() {
+        let baseline = report(1_000_000, Some(1_000_000));
+        let current = report(2_000_000, Some(1_050_000));
+        let regressions = compare(&baseline, &current, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!((regressions[0].day, regressions[0].stage), (7, "part1"));
+        assert_eq!(regressions[0].percent().round(), 100.0);
+    }
+
+    #[test]
+    fn ignores_noise_and_missing_parts() {
+        // Tripled, but only by a few microseconds.
+        let baseline = report(2_000, None);
+        let current = report(6_000, Some(1_000_000));
+        assert!(compare(&baseline, &current, 10.0).is_empty());
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let json = serde_json::to_string(&report(5, None)).unwrap();
+        let report: Report = serde_json::from_str(&json).unwrap();
+        assert_eq!(report.days[0].part1_ns, 5);
+        assert_eq!(report.days[0].part2_ns, None);
+    }
+}
@@ -0,0 +1,141 @@
+use common::{ParseError, Parsed};
+
+/// A single puzzle day, as seen by the runner.
+pub struct Day {
+    pub number: u8,
+    /// Input file shipped with the repository, relative to the workspace root.
+    pub default_input: &'static str,
+    pub parse: fn(&str) -> Result<Box<dyn Parsed>, ParseError>,
+}
+
+pub const DAYS: [Day; 25] = [
+    Day {
+        number: 1,
+        default_input: "day1/locations.txt",
+        parse: common::parse::<day1::Day1>,
+    },
+    Day {
+        number: 2,
+        default_input: "day2/reports.txt",
+        parse: common::parse::<day2::Day2>,
+    },
+    Day {
+        number: 3,
+        default_input: "day3/instructions.txt",
+        parse: common::parse::<day3::Day3>,
+    },
+    Day {
+        number: 4,
+        default_input: "day4/puzzle.txt",
+        parse: common::parse::<day4::Day4>,
+    },
+    Day {
+        number: 5,
+        default_input: "day5/inputs.txt",
+        parse: common::parse::<day5::Day5>,
+    },
+    Day {
+        number: 6,
+        default_input: "day6/map.txt",
+        parse: common::parse::<day6::Day6>,
+    },
+    Day {
+        number: 7,
+        default_input: "day7/equations.txt",
+        parse: common::parse::<day7::Day7>,
+    },
+    Day {
+        number: 8,
+        default_input: "day8/map.txt",
+        parse: common::parse::<day8::Day8>,
+    },
+    Day {
+        number: 9,
+        default_input: "day9/diskmap.txt",
+        parse: common::parse::<day9::Day9>,
+    },
+    Day {
+        number: 10,
+        default_input: "day10/map.txt",
+        parse: common::parse::<day10::Day10>,
+    },
+    Day {
+        number: 11,
+        default_input: "day11/inputs.txt",
+        parse: common::parse::<day11::Day11>,
+    },
+    Day {
+        number: 12,
+        default_input: "day12/plots.txt",
+        parse: common::parse::<day12::Day12>,
+    },
+    Day {
+        number: 13,
+        default_input: "day13/arcade.txt",
+        parse: common::parse::<day13::Day13>,
+    },
+    Day {
+        number: 14,
+        default_input: "day14/robots.txt",
+        parse: common::parse::<day14::Day14>,
+    },
+    Day {
+        number: 15,
+        default_input: "day15/inputs.txt",
+        parse: common::parse::<day15::Day15>,
+    },
+    Day {
+        number: 16,
+        default_input: "day16/maze.txt",
+        parse: common::parse::<day16::Day16>,
+    },
+    Day {
+        number: 17,
+        default_input: "day17/inputs.txt",
+        parse: common::parse::<day17::Day17>,
+    },
+    Day {
+        number: 18,
+        default_input: "day18/coords.txt",
+        parse: common::parse::<day18::Day18>,
+    },
+    Day {
+        number: 19,
+        default_input: "day19/inputs.txt",
+        parse: common::parse::<day19::Day19>,
+    },
+    Day {
+        number: 20,
+        default_input: "day20/input.txt",
+        parse: common::parse::<day20::Day20>,
+    },
+    Day {
+        number: 21,
+        default_input: "day21/inputs.txt",
+        parse: common::parse::<day21::Day21>,
+    },
+    Day {
+        number: 22,
+        default_input: "day22/inputs.txt",
+        parse: common::parse::<day22::Day22>,
+    },
+    Day {
+        number: 23,
+        default_input: "day23/inputs.txt",
+        parse: common::parse::<day23::Day23>,
+    },
+    Day {
+        number: 24,
+        default_input: "day24/inputs.txt",
+        parse: common::parse::<day24::Day24>,
+    },
+    Day {
+        number: 25,
+        default_input: "day25/inputs.txt",
+        parse: common::parse::<day25::Day25>,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
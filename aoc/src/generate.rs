@@ -0,0 +1,1041 @@
+//! Random puzzle inputs in the formats the solvers read, to stress test and benchmark them on
+//! inputs larger than the official ones. The same seed and parameters always produce the same
+//! input.
+
+use grid::{Coords, Direction, Grid};
+use rand::rngs::StdRng;
+use rand::seq::{IteratorRandom, SliceRandom};
+use rand::{Rng, SeedableRng};
+use std::collections::{HashSet, VecDeque};
+
+/// Knobs of a generated input.
+pub struct Params {
+    /// Scale of the input, see [`Generator::size`] for what it counts.
+    pub size: usize,
+    /// Number of pairs of swapped gate outputs in day 24's adder.
+    pub swaps: usize,
+}
+
+/// Input generator of a single day.
+pub struct Generator {
+    pub number: u8,
+    /// What `Params::size` counts.
+    pub size: &'static str,
+    /// Size of the official input.
+    pub default_size: usize,
+    /// Smallest size that makes an input the solvers accept, larger sizes being clamped by some
+    /// generators instead.
+    pub min_size: usize,
+    generate: fn(&mut StdRng, &Params) -> String,
+}
+
+impl Generator {
+    /// The input for `seed`, or why `params` can't be honoured.
+    pub fn generate(&self, seed: u64, params: &Params) -> Result<String, String> {
+        if self.number == 24 {
+            check_adder(params.size, params.swaps)?;
+        }
+        if params.size < self.min_size {
+            return Err(format!(
+                "Day {} needs a size of at least {} ({}), got {}",
+                self.number, self.min_size, self.size, params.size
+            ));
+        }
+        Ok((self.generate)(&mut StdRng::seed_from_u64(seed), params))
+    }
+}
+
+pub const GENERATORS: [Generator; 25] = [
+    Generator {
+        number: 1,
+        size: "pairs of locations",
+        default_size: 1000,
+        min_size: 1,
+        generate: day1,
+    },
+    Generator {
+        number: 2,
+        size: "reports",
+        default_size: 1000,
+        min_size: 1,
+        generate: day2,
+    },
+    Generator {
+        number: 3,
+        size: "instructions",
+        default_size: 1000,
+        min_size: 1,
+        generate: day3,
+    },
+    Generator {
+        number: 4,
+        size: "rows and columns",
+        default_size: 140,
+        min_size: 1,
+        generate: day4,
+    },
+    Generator {
+        number: 5,
+        size: "updates",
+        default_size: 200,
+        min_size: 1,
+        generate: day5,
+    },
+    Generator {
+        number: 6,
+        size: "rows and columns",
+        default_size: 130,
+        min_size: 1,
+        generate: day6,
+    },
+    Generator {
+        number: 7,
+        size: "equations",
+        default_size: 850,
+        min_size: 1,
+        generate: day7,
+    },
+    Generator {
+        number: 8,
+        size: "rows and columns",
+        default_size: 50,
+        min_size: 1,
+        generate: day8,
+    },
+    Generator {
+        number: 9,
+        size: "files",
+        default_size: 10000,
+        min_size: 1,
+        generate: day9,
+    },
+    Generator {
+        number: 10,
+        size: "rows and columns",
+        default_size: 53,
+        min_size: 1,
+        generate: day10,
+    },
+    Generator {
+        number: 11,
+        size: "stones",
+        default_size: 8,
+        min_size: 1,
+        generate: day11,
+    },
+    Generator {
+        number: 12,
+        size: "rows and columns",
+        default_size: 140,
+        min_size: 1,
+        generate: day12,
+    },
+    Generator {
+        number: 13,
+        size: "claw machines",
+        default_size: 320,
+        min_size: 1,
+        generate: day13,
+    },
+    Generator {
+        number: 14,
+        size: "robots, at least the 64 forming the tree",
+        default_size: 500,
+        min_size: 0,
+        generate: day14,
+    },
+    Generator {
+        number: 15,
+        size: "rows and columns",
+        default_size: 50,
+        min_size: 0,
+        generate: day15,
+    },
+    Generator {
+        number: 16,
+        size: "rows and columns, rounded up to odd",
+        default_size: 141,
+        min_size: 0,
+        generate: day16,
+    },
+    Generator {
+        number: 17,
+        size: "octal digits of register A",
+        default_size: 9,
+        min_size: 0,
+        generate: day17,
+    },
+    Generator {
+        number: 18,
+        size: "bytes, or more until the exit is cut off",
+        default_size: 3450,
+        min_size: 0,
+        generate: day18,
+    },
+    Generator {
+        number: 19,
+        size: "designs",
+        default_size: 400,
+        min_size: 1,
+        generate: day19,
+    },
+    Generator {
+        number: 20,
+        size: "rows and columns, rounded up to odd",
+        default_size: 141,
+        min_size: 0,
+        generate: day20,
+    },
+    Generator {
+        number: 21,
+        size: "codes",
+        default_size: 5,
+        min_size: 1,
+        generate: day21,
+    },
+    Generator {
+        number: 22,
+        size: "buyers",
+        default_size: 2250,
+        min_size: 1,
+        generate: day22,
+    },
+    Generator {
+        number: 23,
+        size: "computers, at most 676",
+        default_size: 520,
+        min_size: 0,
+        generate: day23,
+    },
+    Generator {
+        number: 24,
+        size: "bits of the adder",
+        default_size: 45,
+        min_size: 2,
+        generate: day24,
+    },
+    Generator {
+        number: 25,
+        size: "keys and locks",
+        default_size: 500,
+        min_size: 1,
+        generate: day25,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Generator> {
+    GENERATORS
+        .iter()
+        .find(|generator| generator.number == number)
+}
+
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+fn grid(rows: &[Vec<char>]) -> String {
+    lines(rows.iter().map(|row| row.iter().collect()))
+}
+
+/// A `size` x `size` grid of cells picked by `cell`.
+fn random_grid(size: usize, mut cell: impl FnMut() -> char) -> Vec<Vec<char>> {
+    (0..size)
+        .map(|_| (0..size).map(|_| cell()).collect())
+        .collect()
+}
+
+/// Perfect maze of odd side `size`: corridors form a random spanning tree of the cells at odd
+/// coordinates, and everything else is wall.
+fn maze(rng: &mut StdRng, size: usize) -> Vec<Vec<char>> {
+    let mut maze = vec![vec!['#'; size]; size];
+    let mut stack = vec![(1, 1)];
+    maze[1][1] = '.';
+    while let Some(&(row, col)) = stack.last() {
+        let next = [(0, 2), (2, 0), (0, -2), (-2, 0)]
+            .iter()
+            .map(|&(dr, dc)| (row as isize + dr, col as isize + dc))
+            .filter(|&(r, c)| 0 < r && r < size as isize && 0 < c && c < size as isize)
+            .map(|(r, c)| (r as usize, c as usize))
+            .filter(|&(r, c)| maze[r][c] == '#')
+            .choose(rng);
+        match next {
+            Some((r, c)) => {
+                maze[(row + r) / 2][(col + c) / 2] = '.';
+                maze[r][c] = '.';
+                stack.push((r, c));
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    maze
+}
+
+/// Cells on the shortest path between two open cells of `grid`, both included.
+fn open_path(
+    grid: &[Vec<char>],
+    from: (usize, usize),
+    to: (usize, usize),
+) -> Option<Vec<(usize, usize)>> {
+    let mut previous = vec![vec![None; grid[0].len()]; grid.len()];
+    let mut queue = VecDeque::from([from]);
+    previous[from.0][from.1] = Some(from);
+    while let Some((row, col)) = queue.pop_front() {
+        if (row, col) == to {
+            let mut path = vec![to];
+            while *path.last().unwrap() != from {
+                let (r, c) = *path.last().unwrap();
+                path.push(previous[r][c].unwrap());
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for (r, c) in [
+            (row.wrapping_sub(1), col),
+            (row + 1, col),
+            (row, col.wrapping_sub(1)),
+            (row, col + 1),
+        ] {
+            if grid.get(r).and_then(|line| line.get(c)) == Some(&'.') && previous[r][c].is_none() {
+                previous[r][c] = Some((row, col));
+                queue.push_back((r, c));
+            }
+        }
+    }
+    None
+}
+
+fn day1(rng: &mut StdRng, params: &Params) -> String {
+    let left: Vec<u32> = (0..params.size)
+        .map(|_| rng.gen_range(10000..100000))
+        .collect();
+    lines(left.iter().map(|location| {
+        // reuse some locations so that the similarity score is not zero
+        let right = if rng.gen_bool(0.3) {
+            *left.choose(rng).unwrap()
+        } else {
+            rng.gen_range(10000..100000)
+        };
+        format!("{}   {}", location, right)
+    }))
+}
+
+fn day2(rng: &mut StdRng, params: &Params) -> String {
+    lines((0..params.size).map(|_| {
+        let sign = if rng.gen_bool(0.5) { 1 } else { -1 };
+        let mut levels = vec![rng.gen_range(10..90u32)];
+        for _ in 1..rng.gen_range(5..=8) {
+            // mostly safe steps, so that some reports are safe and others one level away
+            let step = if rng.gen_bool(0.9) {
+                sign * rng.gen_range(1..=3)
+            } else {
+                rng.gen_range(-6..=6)
+            };
+            levels.push(levels.last().unwrap().saturating_add_signed(step));
+        }
+        levels
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }))
+}
+
+fn day3(rng: &mut StdRng, params: &Params) -> String {
+    const JUNK: [&str; 12] = [
+        "what()",
+        "who()",
+        "how()",
+        "when()",
+        "where()",
+        "why()",
+        "from()",
+        "select()",
+        "mul[",
+        "mul(4*",
+        "mul ( 2 , 4 )",
+        "don't",
+    ];
+    const PUNCTUATION: &[u8] = b"!@#$%^&*()[]{}<>+-~,:;?'/ ";
+    let mut tokens: Vec<String> = (0..params.size)
+        .map(|_| match rng.gen_range(0..10) {
+            0 => "do()".to_string(),
+            1 => "don't()".to_string(),
+            _ => format!("mul({},{})", rng.gen_range(1..1000), rng.gen_range(1..1000)),
+        })
+        .collect();
+    for _ in 0..params.size * 2 {
+        let junk = if rng.gen_bool(0.5) {
+            JUNK.choose(rng).unwrap().to_string()
+        } else {
+            (*PUNCTUATION.choose(rng).unwrap() as char).to_string()
+        };
+        tokens.insert(rng.gen_range(0..=tokens.len()), junk);
+    }
+    // lines of roughly 3000 characters, like the official input
+    let mut lines = vec![String::new()];
+    for token in tokens {
+        if lines.last().unwrap().len() > 3000 {
+            lines.push(String::new());
+        }
+        lines.last_mut().unwrap().push_str(&token);
+    }
+    self::lines(lines)
+}
+
+fn day4(rng: &mut StdRng, params: &Params) -> String {
+    grid(&random_grid(params.size, || {
+        *b"XMAS".choose(rng).unwrap() as char
+    }))
+}
+
+fn day5(rng: &mut StdRng, params: &Params) -> String {
+    // every pair of pages is ordered, consistently with one order of all pages
+    let mut order: Vec<u32> = (10..100).collect();
+    order.shuffle(rng);
+    order.truncate(49);
+    let mut rules: Vec<String> = (0..order.len())
+        .flat_map(|i| (i + 1..order.len()).map(move |j| (i, j)))
+        .map(|(i, j)| format!("{}|{}", order[i], order[j]))
+        .collect();
+    rules.shuffle(rng);
+    let updates = (0..params.size).map(|_| {
+        let length = 2 * rng.gen_range(2..=11) + 1;
+        let mut positions: Vec<usize> = (0..order.len()).choose_multiple(rng, length);
+        if rng.gen_bool(0.5) {
+            positions.sort();
+        } else {
+            positions.shuffle(rng);
+        }
+        let pages: Vec<String> = positions.iter().map(|&i| order[i].to_string()).collect();
+        pages.join(",")
+    });
+    lines(rules) + "\n" + &lines(updates)
+}
+
+/// Number of positions the guard starting at `start` and facing up visits before walking off
+/// `map`, or `None` if it walks in circles.
+fn guard_walk(map: &Grid<char>, start: Coords) -> Option<usize> {
+    let mut seen = HashSet::new();
+    let (mut position, mut direction) = (start, Direction::Up);
+    while seen.insert((position, direction)) {
+        match map.walk(position, direction) {
+            None => {
+                let visited: HashSet<_> = seen.iter().map(|&(position, _)| position).collect();
+                return Some(visited.len());
+            }
+            Some(next) if map[next] == '#' => direction = direction.turn_right(),
+            Some(next) => position = next,
+        }
+    }
+    None
+}
+
+fn day6(rng: &mut StdRng, params: &Params) -> String {
+    // the guard starts near the middle and patrols for a while before leaving
+    let size = params.size;
+    loop {
+        let mut map = Grid::new(random_grid(size, || {
+            if rng.gen_bool(0.05) {
+                '#'
+            } else {
+                '.'
+            }
+        }));
+        let start = (
+            rng.gen_range(size / 4..=size * 3 / 4),
+            rng.gen_range(size / 4..=size * 3 / 4),
+        );
+        map[start] = '^';
+        if guard_walk(&map, start).is_some_and(|visited| visited >= size) {
+            return map.to_string();
+        }
+    }
+}
+
+fn day7(rng: &mut StdRng, params: &Params) -> String {
+    lines((0..params.size).map(|_| loop {
+        let values: Vec<u64> = (0..rng.gen_range(3..=12))
+            .map(|_| {
+                if rng.gen_bool(0.7) {
+                    rng.gen_range(1..10)
+                } else {
+                    rng.gen_range(10..1000)
+                }
+            })
+            .collect();
+        let target =
+            values[1..]
+                .iter()
+                .try_fold(values[0], |acc, &value| match rng.gen_range(0..3) {
+                    0 => acc.checked_add(value),
+                    1 => acc.checked_mul(value),
+                    _ => format!("{}{}", acc, value).parse().ok(),
+                });
+        // about half of the equations cannot be made true
+        let target = target
+            .filter(|&target| target < 10u64.pow(15))
+            .map(|target| {
+                if rng.gen_bool(0.5) {
+                    target + rng.gen_range(1..10)
+                } else {
+                    target
+                }
+            });
+        if let Some(target) = target {
+            let values: Vec<String> = values.iter().map(u64::to_string).collect();
+            break format!("{}: {}", target, values.join(" "));
+        }
+    }))
+}
+
+fn day8(rng: &mut StdRng, params: &Params) -> String {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let size = params.size;
+    let mut map = vec![vec!['.'; size]; size];
+    let frequencies = (size * size / 60).clamp(1, FREQUENCIES.len());
+    let antennas = (0..size * size).choose_multiple(rng, frequencies * 4);
+    for (idx, position) in antennas.into_iter().enumerate() {
+        map[position / size][position % size] = FREQUENCIES[idx / 4] as char;
+    }
+    grid(&map)
+}
+
+fn day9(rng: &mut StdRng, params: &Params) -> String {
+    let mut disk_map = rng.gen_range(1..=9).to_string();
+    for _ in 1..params.size {
+        disk_map.push_str(&format!("{}{}", rng.gen_range(0..=9), rng.gen_range(1..=9)));
+    }
+    disk_map + "\n"
+}
+
+fn day10(rng: &mut StdRng, params: &Params) -> String {
+    let size = params.size;
+    let mut map = random_grid(size, || char::from_digit(rng.gen_range(0..10), 10).unwrap());
+    // random heights hardly form trails, so lay some from 0 to 9
+    for _ in 0..size * size / 20 {
+        let (mut row, mut col) = (rng.gen_range(0..size), rng.gen_range(0..size));
+        for height in 0..10 {
+            map[row][col] = char::from_digit(height, 10).unwrap();
+            let next = [(0, 1), (1, 0), (0, -1), (-1, 0)]
+                .choose(rng)
+                .map(|&(dr, dc)| (row as isize + dr, col as isize + dc))
+                .filter(|&(r, c)| 0 <= r && r < size as isize && 0 <= c && c < size as isize);
+            match next {
+                Some((r, c)) => (row, col) = (r as usize, c as usize),
+                None => break,
+            }
+        }
+    }
+    grid(&map)
+}
+
+fn day11(rng: &mut StdRng, params: &Params) -> String {
+    let stones: Vec<String> = (0..params.size)
+        .map(|_| rng.gen_range(0..10_000_000u64).to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
+
+fn day12(rng: &mut StdRng, params: &Params) -> String {
+    // each plot grows the plant of the nearest seed, with a few strays
+    let size = params.size;
+    let seeds: Vec<(usize, usize, char)> = (0..(size * size / 100).max(1))
+        .map(|_| {
+            let plant = rng.gen_range(b'A'..=b'Z') as char;
+            (rng.gen_range(0..size), rng.gen_range(0..size), plant)
+        })
+        .collect();
+    let map: Vec<Vec<char>> = (0..size)
+        .map(|row| {
+            (0..size)
+                .map(|col| {
+                    if rng.gen_bool(0.02) {
+                        return rng.gen_range(b'A'..=b'Z') as char;
+                    }
+                    let nearest = seeds
+                        .iter()
+                        .min_by_key(|&&(r, c, _)| row.abs_diff(r) + col.abs_diff(c))
+                        .unwrap();
+                    nearest.2
+                })
+                .collect()
+        })
+        .collect();
+    grid(&map)
+}
+
+fn day13(rng: &mut StdRng, params: &Params) -> String {
+    let machines: Vec<String> = (0..params.size)
+        .map(|_| {
+            let (a, b) = loop {
+                let a = (rng.gen_range(10..100u64), rng.gen_range(10..100u64));
+                let b = (rng.gen_range(10..100u64), rng.gen_range(10..100u64));
+                if a.0 * b.1 != a.1 * b.0 {
+                    break (a, b);
+                }
+            };
+            let prize = if rng.gen_bool(0.7) {
+                let (presses_a, presses_b) = (rng.gen_range(0..=100), rng.gen_range(0..=100));
+                (
+                    presses_a * a.0 + presses_b * b.0,
+                    presses_a * a.1 + presses_b * b.1,
+                )
+            } else {
+                (rng.gen_range(1000..20000), rng.gen_range(1000..20000))
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect();
+    machines.join("\n")
+}
+
+fn day14(rng: &mut StdRng, params: &Params) -> String {
+    const WIDTH: i64 = 101;
+    const HEIGHT: i64 = 103;
+    // positions at the time the tree appears: a filled triangle, and the rest scattered
+    let (top_x, top_y) = (rng.gen_range(10..WIDTH - 10), rng.gen_range(0..HEIGHT - 10));
+    let mut positions: Vec<(i64, i64)> = (0..8)
+        .flat_map(|row| (-row..=row).map(move |col| (top_x + col, top_y + row)))
+        .collect();
+    while positions.len() < params.size {
+        positions.push((rng.gen_range(0..WIDTH), rng.gen_range(0..HEIGHT)));
+    }
+    let time = rng.gen_range(100..WIDTH * HEIGHT);
+    lines(positions.into_iter().map(|(x, y)| {
+        let velocity = (rng.gen_range(-99..=99), rng.gen_range(-99..=99));
+        format!(
+            "p={},{} v={},{}",
+            (x - velocity.0 * time).rem_euclid(WIDTH),
+            (y - velocity.1 * time).rem_euclid(HEIGHT),
+            velocity.0,
+            velocity.1
+        )
+    }))
+}
+
+fn day15(rng: &mut StdRng, params: &Params) -> String {
+    let size = params.size.max(3);
+    let mut map = random_grid(size, || match rng.gen_range(0..100) {
+        0..10 => '#',
+        10..35 => 'O',
+        _ => '.',
+    });
+    map[0].fill('#');
+    map[size - 1].fill('#');
+    for row in map.iter_mut() {
+        row[0] = '#';
+        row[size - 1] = '#';
+    }
+    map[rng.gen_range(1..size - 1)][rng.gen_range(1..size - 1)] = '@';
+    let moves: Vec<char> = (0..size * size * 8)
+        .map(|_| *b"^>v<".choose(rng).unwrap() as char)
+        .collect();
+    grid(&map) + "\n" + &lines(moves.chunks(1000).map(|line| line.iter().collect()))
+}
+
+fn day16(rng: &mut StdRng, params: &Params) -> String {
+    let size = params.size.max(5) | 1;
+    let mut maze = maze(rng, size);
+    // knock down some inner walls, so that there are several paths to choose from
+    for (row, line) in maze.iter_mut().enumerate().take(size - 1).skip(1) {
+        for (col, cell) in line.iter_mut().enumerate().take(size - 1).skip(1) {
+            if (row + col) % 2 == 1 && rng.gen_bool(0.05) {
+                *cell = '.';
+            }
+        }
+    }
+    maze[size - 2][1] = 'S';
+    maze[1][size - 2] = 'E';
+    grid(&maze)
+}
+
+/// Whether some value of register A makes `program` output itself, searching A three bits at
+/// a time from the last output backwards.
+fn has_quine(program: &[u64]) -> bool {
+    fn search(program: &[u64], a: u64, outputs: usize) -> bool {
+        outputs == program.len()
+            || (0..8).any(|bits| {
+                let a = a << 3 | bits;
+                let expected = &program[program.len() - outputs - 1..];
+                day17::Computer::new(a, 0, 0, program.to_vec()).compute() == expected
+                    && search(program, a, outputs + 1)
+            })
+    }
+    search(program, 0, 0)
+}
+
+fn day17(rng: &mut StdRng, params: &Params) -> String {
+    // the shape of the official programs, which shift A by three bits each output
+    let program = loop {
+        let program = vec![
+            2,
+            4,
+            1,
+            rng.gen_range(0..8),
+            7,
+            5,
+            1,
+            rng.gen_range(0..8),
+            0,
+            3,
+            4,
+            0,
+            5,
+            5,
+            3,
+            0,
+        ];
+        if has_quine(&program) {
+            break program;
+        }
+    };
+    let digits = params.size.clamp(1, 21) as u32;
+    let a = rng.gen_range(8u64.pow(digits - 1)..8u64.pow(digits));
+    let program: Vec<String> = program.iter().map(u64::to_string).collect();
+    format!(
+        "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+        a,
+        program.join(",")
+    )
+}
+
+fn day18(rng: &mut StdRng, params: &Params) -> String {
+    const SIZE: usize = 71;
+    const FIRST_BYTES: usize = 1024;
+    let (bytes, cut_off) = loop {
+        let mut bytes: Vec<(usize, usize)> = (0..SIZE * SIZE)
+            .map(|idx| (idx % SIZE, idx / SIZE))
+            .filter(|&byte| byte != (0, 0) && byte != (SIZE - 1, SIZE - 1))
+            .collect();
+        bytes.shuffle(rng);
+        // first byte that cuts off the exit, found by bisection
+        let (mut left, mut right) = (0, bytes.len());
+        while left < right {
+            let mid = (left + right) / 2;
+            let mut memory = vec![vec!['.'; SIZE]; SIZE];
+            for &(x, y) in &bytes[..=mid] {
+                memory[y][x] = '#';
+            }
+            match open_path(&memory, (0, 0), (SIZE - 1, SIZE - 1)) {
+                Some(_) => left = mid + 1,
+                None => right = mid,
+            }
+        }
+        if left >= FIRST_BYTES {
+            break (bytes, left);
+        }
+    };
+    let count = params.size.max(cut_off + 1);
+    lines(bytes[..count].iter().map(|(x, y)| format!("{},{}", x, y)))
+}
+
+fn day19(rng: &mut StdRng, params: &Params) -> String {
+    const COLORS: &[u8] = b"wubrg";
+    // like the official input, every color but one is a towel on its own. No towel ends with
+    // that color or has it followed by white, so designs with "gw" cannot be made.
+    let mut patterns: HashSet<String> = ["w", "u", "b", "r"].map(String::from).into();
+    while patterns.len() < params.size.max(10) {
+        let pattern: String = (0..rng.gen_range(2..=8))
+            .map(|_| *COLORS.choose(rng).unwrap() as char)
+            .collect();
+        if !pattern.ends_with('g') && !pattern.contains("gw") {
+            patterns.insert(pattern);
+        }
+    }
+    let mut patterns: Vec<String> = patterns.into_iter().collect();
+    patterns.sort();
+    patterns.shuffle(rng);
+    let designs = (0..params.size).map(|_| {
+        let length = rng.gen_range(40..=60);
+        let impossible = rng.gen_bool(0.4);
+        let mut design = String::new();
+        while design.len() < length {
+            design.push_str(patterns.choose(rng).unwrap());
+            if impossible && design.len() >= length / 2 && !design.contains("gw") {
+                design.push_str("gw");
+            }
+        }
+        design
+    });
+    patterns.join(", ") + "\n\n" + &lines(designs)
+}
+
+fn day20(rng: &mut StdRng, params: &Params) -> String {
+    // a single corridor: the path between two corners of a perfect maze, walled in
+    let size = params.size.max(5) | 1;
+    let maze = maze(rng, size);
+    let (start, end) = ((size - 2, 1), (1, size - 2));
+    let mut track = vec![vec!['#'; size]; size];
+    for (row, col) in open_path(&maze, start, end).unwrap() {
+        track[row][col] = '.';
+    }
+    track[start.0][start.1] = 'S';
+    track[end.0][end.1] = 'E';
+    grid(&track)
+}
+
+fn day21(rng: &mut StdRng, params: &Params) -> String {
+    lines((0..params.size).map(|_| format!("{:03}A", rng.gen_range(1..1000))))
+}
+
+fn day22(rng: &mut StdRng, params: &Params) -> String {
+    lines((0..params.size).map(|_| rng.gen_range(1..1 << 24).to_string()))
+}
+
+fn day23(rng: &mut StdRng, params: &Params) -> String {
+    let mut names: Vec<String> = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .collect();
+    names.shuffle(rng);
+    names.truncate(params.size.clamp(3, names.len()));
+    let count = names.len();
+    // one large clique, among sparse random connections with 13 neighbors on average
+    let mut edges: HashSet<(usize, usize)> = HashSet::new();
+    let clique = count.min(13);
+    for a in 0..clique {
+        for b in a + 1..clique {
+            edges.insert((a, b));
+        }
+    }
+    let target = (count * 13 / 2).min(count * (count - 1) / 2);
+    while edges.len() < target {
+        let (a, b) = (rng.gen_range(0..count), rng.gen_range(0..count));
+        if a != b {
+            edges.insert((a.min(b), a.max(b)));
+        }
+    }
+    let mut edges: Vec<(usize, usize)> = edges.into_iter().collect();
+    edges.sort();
+    edges.shuffle(rng);
+    lines(edges.into_iter().map(|(a, b)| {
+        if rng.gen_bool(0.5) {
+            format!("{}-{}", names[a], names[b])
+        } else {
+            format!("{}-{}", names[b], names[a])
+        }
+    }))
+}
+
+/// Largest adder whose wires day 24 can read: `z{bits}` has to be below `z64`.
+const MAX_ADDER_BITS: usize = 63;
+
+/// Checks that a `bits`-bit adder has room for `swaps` swaps. Only the full adders between the
+/// lowest and the highest bit get swapped outputs, so there are `bits - 2` of them.
+fn check_adder(bits: usize, swaps: usize) -> Result<(), String> {
+    if !(2..=MAX_ADDER_BITS).contains(&bits) {
+        return Err(format!(
+            "The adder needs 2 to {} bits, got {}",
+            MAX_ADDER_BITS, bits
+        ));
+    }
+    if swaps > bits - 2 {
+        return Err(format!(
+            "A {}-bit adder can swap outputs in at most {} of its full adders, got {} swaps",
+            bits,
+            bits - 2,
+            swaps
+        ));
+    }
+    Ok(())
+}
+
+/// A ripple-carry adder of `bits` bits with outputs swapped in `swaps` of its full adders, as
+/// the input and the sorted swapped wires. `bits` and `swaps` have to pass [`check_adder`].
+fn swapped_adder(rng: &mut StdRng, bits: usize, swaps: usize) -> (String, Vec<String>) {
+    let mut taken: HashSet<String> = HashSet::new();
+    let mut wire = |rng: &mut StdRng| loop {
+        let name: String = (0..3).map(|_| rng.gen_range(b'a'..b'x') as char).collect();
+        if taken.insert(name.clone()) {
+            break name;
+        }
+    };
+    // gates as [x, op, y, output]
+    let mut gates: Vec<[String; 4]> = Vec::new();
+    let gate = |x: &str, op: &str, y: &str, z: &str| [x, op, y, z].map(String::from);
+    let mut carry = wire(rng);
+    gates.push(gate("x00", "XOR", "y00", "z00"));
+    gates.push(gate("x00", "AND", "y00", &carry));
+    let mut swapped = Vec::new();
+    let swapped_bits: Vec<usize> = (1..bits - 1).choose_multiple(rng, swaps);
+    for bit in 1..bits {
+        let (x, y) = (format!("x{:02}", bit), format!("y{:02}", bit));
+        let [sum, and1, and2] = [wire(rng), wire(rng), wire(rng)];
+        let z = format!("z{:02}", bit);
+        let carry_out = if bit == bits - 1 {
+            format!("z{:02}", bits)
+        } else {
+            wire(rng)
+        };
+        let first = gates.len();
+        gates.push(gate(&x, "XOR", &y, &sum));
+        gates.push(gate(&x, "AND", &y, &and1));
+        gates.push(gate(&sum, "XOR", &carry, &z));
+        gates.push(gate(&sum, "AND", &carry, &and2));
+        gates.push(gate(&and1, "OR", &and2, &carry_out));
+        if swapped_bits.contains(&bit) {
+            // swaps that keep the circuit free of loops: XOR1 and AND1, or z and another output
+            let (a, b) = [(0, 1), (2, 4), (2, 3), (2, 1)][rng.gen_range(0..4)];
+            let (a, b) = (first + a, first + b);
+            let output = std::mem::take(&mut gates[a][3]);
+            gates[a][3] = std::mem::replace(&mut gates[b][3], output);
+            swapped.extend([gates[a][3].clone(), gates[b][3].clone()]);
+        }
+        carry = carry_out;
+    }
+    gates.shuffle(rng);
+    let values = ["x", "y"].iter().flat_map(|prefix| {
+        (0..bits)
+            .map(|bit| format!("{}{:02}: {}", prefix, bit, rng.gen_range(0..2)))
+            .collect::<Vec<_>>()
+    });
+    let values = lines(values.collect::<Vec<_>>());
+    let gates = lines(gates.into_iter().map(|[x, op, y, z]| {
+        if rng.gen_bool(0.5) {
+            format!("{} {} {} -> {}", x, op, y, z)
+        } else {
+            format!("{} {} {} -> {}", y, op, x, z)
+        }
+    }));
+    swapped.sort();
+    (values + "\n" + &gates, swapped)
+}
+
+fn day24(rng: &mut StdRng, params: &Params) -> String {
+    swapped_adder(rng, params.size, params.swaps).0
+}
+
+fn day25(rng: &mut StdRng, params: &Params) -> String {
+    let schematics: Vec<String> = (0..params.size)
+        .map(|_| {
+            let is_lock = rng.gen_bool(0.5);
+            let heights: Vec<usize> = (0..5).map(|_| rng.gen_range(0..=5)).collect();
+            let rows = (0..7).map(|row| {
+                heights
+                    .iter()
+                    .map(|&height| {
+                        let filled = if is_lock {
+                            row <= height
+                        } else {
+                            row >= 6 - height
+                        };
+                        if filled {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect()
+            });
+            lines(rows)
+        })
+        .collect();
+    schematics.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn small(generator: &Generator) -> Params {
+        Params {
+            size: if generator.number == 24 { 12 } else { 20 },
+            swaps: 4,
+        }
+    }
+
+    #[test]
+    fn inputs_are_solvable() {
+        for generator in &GENERATORS {
+            let day = crate::days::find(generator.number).unwrap();
+            for seed in 0..2 {
+                let input = generator.generate(seed, &small(generator)).unwrap();
+                let parsed = (day.parse)(&input)
+                    .unwrap_or_else(|e| panic!("day {} seed {}: {}", generator.number, seed, e));
+                parsed.part1();
+                parsed.part2();
+            }
+        }
+    }
+
+    #[test]
+    fn inputs_are_reproducible() {
+        for generator in &GENERATORS {
+            let params = small(generator);
+            assert_eq!(
+                generator.generate(7, &params).unwrap(),
+                generator.generate(7, &params).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_impossible_adders() {
+        let day24 = &GENERATORS[23];
+        let generate = |size, swaps| day24.generate(0, &Params { size, swaps });
+        assert_eq!(
+            generate(3, 10).unwrap_err(),
+            "A 3-bit adder can swap outputs in at most 1 of its full adders, got 10 swaps"
+        );
+        assert_eq!(
+            generate(0, 0).unwrap_err(),
+            "The adder needs 2 to 63 bits, got 0"
+        );
+        assert!(generate(64, 4).is_err());
+        assert!(generate(2, 0).is_ok());
+        assert!(generate(6, 4).is_ok());
+    }
+
+    #[test]
+    fn rejects_too_small_inputs() {
+        assert_eq!(
+            GENERATORS[5]
+                .generate(0, &Params { size: 0, swaps: 0 })
+                .unwrap_err(),
+            "Day 6 needs a size of at least 1 (rows and columns), got 0"
+        );
+        for generator in &GENERATORS {
+            let day = crate::days::find(generator.number).unwrap();
+            let size = generator.min_size;
+            let input = generator.generate(0, &Params { size, swaps: 0 }).unwrap();
+            let parsed = (day.parse)(&input)
+                .unwrap_or_else(|e| panic!("day {} size {}: {}", generator.number, size, e));
+            parsed.part1();
+            parsed.part2();
+            if let Some(size) = size.checked_sub(1) {
+                assert!(generator.generate(0, &Params { size, swaps: 0 }).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn swapped_wires_are_found() {
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let (input, swapped) = swapped_adder(&mut rng, 45, 4);
+            let (_, instructions) = day24::parse_inputs(&input).unwrap();
+            assert_eq!(
+                day24::swapped_wires(&instructions),
+                swapped,
+                "seed {}",
+                seed
+            );
+        }
+    }
+
+    #[test]
+    fn track_is_a_single_corridor() {
+        let input = day20(&mut StdRng::seed_from_u64(1), &small(&GENERATORS[19]));
+        let maze = day20::Maze::new(&input).unwrap();
+        let path = maze.path();
+        let open = input
+            .chars()
+            .filter(|&c| matches!(c, '.' | 'S' | 'E'))
+            .count();
+        assert_eq!(path.len(), open);
+    }
+}
@@ -0,0 +1,230 @@
+mod bench;
+mod days;
+mod generate;
+mod output;
+
+use clap::{Parser, Subcommand};
+use output::Format;
+use std::error::Error;
+use std::path::PathBuf;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solver against an input file
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Part to run, both parts if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, defaults to the input shipped with the day
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Output format; debug output always goes to stderr
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Generate a random input for a day, in the format of the official one
+    Generate {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Seed of the random generator; the same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Scale of the input, e.g. lines or grid side, defaults to the official input's
+        #[arg(long)]
+        size: Option<usize>,
+        /// Number of pairs of swapped gate outputs in day 24's adder
+        #[arg(long, default_value_t = 4)]
+        swaps: usize,
+        /// File to write the input to, stdout if omitted
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Time parsing and both parts of each day on its shipped input
+    Bench {
+        /// Days to benchmark, all of them if omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Vec<u8>,
+        /// Number of runs each reported timing is the median of
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u64).range(1..))]
+        runs: u64,
+        /// Write the timings as JSON to this file
+        #[arg(long)]
+        output: Option<PathBuf>,
+        /// JSON report of an earlier run to compare against
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Percentage a timing may grow over the baseline before it counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn run(
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let day = days::find(day).ok_or(format!("Day {} is not solved", day))?;
+    let path = input.unwrap_or_else(|| workspace_root().join(day.default_input));
+    let input = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    let (parsed, parse_ns, diagnostics) = output::measure(|| (day.parse)(&input));
+    let parsed = parsed.map_err(|e| e.with_file(&path))?;
+    let mut parts = Vec::new();
+    if part.unwrap_or(1) == 1 {
+        parts.extend(output::solve(parsed.as_ref(), 1));
+    }
+    if part.unwrap_or(2) == 2 {
+        match output::solve(parsed.as_ref(), 2) {
+            Some(result) => parts.push(result),
+            None if part.is_some() => {
+                return Err(format!("Day {} has no part 2", day.number).into())
+            }
+            None => {}
+        }
+    }
+
+    match format {
+        Format::Text => {
+            for result in &parts {
+                println!("Part {}: {}", result.part, result.answer);
+            }
+        }
+        Format::Json => {
+            let run = output::RunResult {
+                day: day.number,
+                input: path.display().to_string(),
+                parse_ns,
+                diagnostics,
+                parts,
+            };
+            println!("{}", serde_json::to_string(&run)?);
+        }
+    }
+    Ok(())
+}
+
+fn generate(
+    day: u8,
+    seed: u64,
+    size: Option<usize>,
+    swaps: usize,
+    output: Option<PathBuf>,
+) -> Result<(), Box<dyn Error>> {
+    let generator = generate::find(day).ok_or(format!("Day {} has no generator", day))?;
+    let params = generate::Params {
+        size: size.unwrap_or(generator.default_size),
+        swaps,
+    };
+    let input = generator.generate(seed, &params)?;
+    match output {
+        Some(path) => std::fs::write(&path, input)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?,
+        None => print!("{}", input),
+    }
+    eprintln!(
+        "Day {}: {} {}, seed {}",
+        day, params.size, generator.size, seed
+    );
+    Ok(())
+}
+
+fn bench(
+    days: Vec<u8>,
+    runs: usize,
+    output: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+) -> Result<(), Box<dyn Error>> {
+    let baseline = baseline
+        .map(|path| bench::Report::load(&path))
+        .transpose()?;
+    let mut report = bench::Report {
+        runs,
+        days: Vec::new(),
+    };
+    for day in days::DAYS.iter() {
+        if !days.is_empty() && !days.contains(&day.number) {
+            continue;
+        }
+        let path = workspace_root().join(day.default_input);
+        let input = std::fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let timings =
+            bench::time_day(day, &input, runs).map_err(|e| format!("{}: {}", path.display(), e))?;
+        bench::print_timings(&timings);
+        report.days.push(timings);
+    }
+    if let Some(output) = output {
+        report.save(&output)?;
+    }
+
+    let Some(baseline) = baseline else {
+        return Ok(());
+    };
+    let regressions = bench::compare(&baseline, &report, threshold);
+    for regression in &regressions {
+        println!(
+            "Regression: day {} {} took {} instead of {} (+{:.0}%)",
+            regression.day,
+            regression.stage,
+            bench::format_duration(regression.current),
+            bench::format_duration(regression.baseline),
+            regression.percent(),
+        );
+    }
+    if !regressions.is_empty() {
+        return Err(format!(
+            "{} timings regressed by more than {}%",
+            regressions.len(),
+            threshold
+        )
+        .into());
+    }
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+        } => run(day, part, input, format),
+        Command::Generate {
+            day,
+            seed,
+            size,
+            swaps,
+            output,
+        } => generate(day, seed, size, swaps, output),
+        Command::Bench {
+            day,
+            runs,
+            output,
+            baseline,
+            threshold,
+        } => bench(day, runs as usize, output, baseline, threshold),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
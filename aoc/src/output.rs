@@ -0,0 +1,96 @@
+use common::diagnostics;
+use common::Parsed;
+use serde::Serialize;
+use std::time::Instant;
+
+/// How `aoc run` prints its results.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, clap::ValueEnum)]
+pub enum Format {
+    /// `Part N: answer` lines
+    Text,
+    /// A single JSON object with answers, timings and diagnostics
+    Json,
+}
+
+/// Result of solving one part.
+#[derive(Debug, Serialize)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub elapsed_ns: u64,
+    /// Debug messages the solver emitted while solving this part.
+    pub diagnostics: Vec<String>,
+}
+
+/// Everything `aoc run --format json` prints for one run.
+#[derive(Debug, Serialize)]
+pub struct RunResult {
+    pub day: u8,
+    pub input: String,
+    pub parse_ns: u64,
+    /// Debug messages the solver emitted while parsing.
+    pub diagnostics: Vec<String>,
+    pub parts: Vec<PartResult>,
+}
+
+/// Runs `f`, returning its result, how long it took and the diagnostics it emitted.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, u64, Vec<String>) {
+    let ((result, elapsed), diagnostics) = diagnostics::capture(|| {
+        let start = Instant::now();
+        let result = f();
+        (result, start.elapsed().as_nanos() as u64)
+    });
+    (result, elapsed, diagnostics)
+}
+
+/// Solves `part` of `parsed`, or returns `None` if the day has no such part.
+pub fn solve(parsed: &dyn Parsed, part: u8) -> Option<PartResult> {
+    let (answer, elapsed_ns, diagnostics) = measure(|| match part {
+        1 => Some(parsed.part1()),
+        _ => parsed.part2(),
+    });
+    Some(PartResult {
+        part,
+        answer: answer?,
+        elapsed_ns,
+        diagnostics,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_runs() {
+        let run = RunResult {
+            day: 24,
+            input: "day24/inputs.txt".to_string(),
+            parse_ns: 10,
+            diagnostics: vec![],
+            parts: vec![PartResult {
+                part: 2,
+                answer: "a,b".to_string(),
+                elapsed_ns: 20,
+                diagnostics: vec!["z12 is not an OR".to_string()],
+            }],
+        };
+        assert_eq!(
+            serde_json::to_string(&run).unwrap(),
+            r#"{"day":24,"input":"day24/inputs.txt","parse_ns":10,"diagnostics":[],"parts":[{"part":2,"answer":"a,b","elapsed_ns":20,"diagnostics":["z12 is not an OR"]}]}"#
+        );
+    }
+
+    #[test]
+    fn measures_diagnostics() {
+        let (answer, _, diagnostics) = measure(|| {
+            common::diagnostic!("working");
+            // like another day solved by a parallel test
+            std::thread::spawn(|| common::diagnostic!("elsewhere"))
+                .join()
+                .unwrap();
+            7
+        });
+        assert_eq!((answer, diagnostics), (7, vec!["working".to_string()]));
+    }
+}
@@ -0,0 +1,108 @@
+//! Debug messages solvers emit besides their answers.
+//!
+//! Messages always go to stderr, keeping stdout for answers. While [`capture`] runs, the
+//! messages emitted on its thread are also collected, so the runner can include them in its
+//! JSON output. Threads spawned meanwhile only contribute if they [`Sink::run`] the [`current`]
+//! sink, so concurrent captures, e.g. of parallel tests, never see each other's messages.
+
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::sync::{Arc, Mutex};
+
+thread_local! {
+    static SINK: RefCell<Option<Sink>> = const { RefCell::new(None) };
+}
+
+/// Collects the messages of one [`capture`].
+#[derive(Clone, Debug, Default)]
+pub struct Sink(Arc<Mutex<Vec<String>>>);
+
+impl Sink {
+    /// Runs `f` with the messages emitted on the current thread going into this sink.
+    pub fn run<T>(&self, f: impl FnOnce() -> T) -> T {
+        /// Puts back the thread's previous sink, even if `f` panics.
+        struct Restore(Option<Sink>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                SINK.with(|sink| *sink.borrow_mut() = self.0.take());
+            }
+        }
+
+        let _restore = Restore(SINK.with(|sink| sink.replace(Some(self.clone()))));
+        f()
+    }
+}
+
+/// The sink of the capture running on the current thread, if any.
+pub fn current() -> Option<Sink> {
+    SINK.with(|sink| sink.borrow().clone())
+}
+
+/// Writes `message` to stderr, and records it if a [`capture`] is active on this thread.
+pub fn emit(message: impl Display) {
+    let message = message.to_string();
+    eprintln!("{}", message);
+    if let Some(sink) = current() {
+        sink.0.lock().unwrap().push(message);
+    }
+}
+
+/// Runs `f`, returning its result along with all messages it emitted meanwhile.
+pub fn capture<T>(f: impl FnOnce() -> T) -> (T, Vec<String>) {
+    let sink = Sink::default();
+    let result = sink.run(f);
+    let messages = std::mem::take(&mut *sink.0.lock().unwrap());
+    (result, messages)
+}
+
+/// Formats and [`emit`]s a diagnostic message, like `eprintln!`.
+#[macro_export]
+macro_rules! diagnostic {
+    ($($arg:tt)*) => {
+        $crate::diagnostics::emit(format!($($arg)*))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn captures_messages() {
+        let (answer, messages) = capture(|| {
+            diagnostic!("checked {} wires", 3);
+            let sink = current().unwrap();
+            std::thread::spawn(move || sink.run(|| emit("from a helper thread")))
+                .join()
+                .unwrap();
+            std::thread::spawn(|| emit("from an unrelated thread"))
+                .join()
+                .unwrap();
+            42
+        });
+        assert_eq!(answer, 42);
+        assert_eq!(messages, ["checked 3 wires", "from a helper thread"]);
+        emit("not captured");
+        assert_eq!(capture(|| ()).1, Vec::<String>::new());
+    }
+
+    #[test]
+    fn keeps_captures_apart() {
+        let (outer, messages) = capture(|| {
+            emit("outer");
+            let inner = capture(|| emit("inner")).1;
+            // a capture running meanwhile on another thread
+            let other = std::thread::spawn(|| capture(|| emit("other")).1)
+                .join()
+                .unwrap();
+            emit("outer again");
+            (inner, other)
+        });
+        assert_eq!(
+            outer,
+            (vec!["inner".to_string()], vec!["other".to_string()])
+        );
+        assert_eq!(messages, ["outer", "outer again"]);
+    }
+}
@@ -0,0 +1,86 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// Error raised while parsing a puzzle input, pointing at the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Set by whoever read the input from disk, see [`ParseError::with_file`].
+    pub file: Option<PathBuf>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// Error at `token`, which has to be a subslice of `input` so its position can be recovered.
+    pub fn at(input: &str, token: &str, message: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|&offset| offset <= input.len())
+            .expect("token is not part of the input");
+        let (line, column) = position(input, offset);
+        ParseError {
+            file: None,
+            line,
+            column,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Error for something that should have been in `input` but is not, located at its end.
+    pub fn missing(input: &str, what: &str) -> Self {
+        Self::at(input, &input[input.len()..], format!("missing {}", what))
+    }
+
+    pub fn with_file(mut self, file: impl AsRef<Path>) -> Self {
+        self.file = Some(file.as_ref().to_path_buf());
+        self
+    }
+}
+
+/// 1-based line and column of the byte `offset` in `input`.
+fn position(input: &str, offset: usize) -> (usize, usize) {
+    let before = &input[..offset];
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    let column = before[line_start..].chars().count() + 1;
+    (line, column)
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)?;
+        if !self.token.is_empty() {
+            write!(f, " '{}'", self.token)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, a subslice of `input`, reporting its position if it is not a valid `T`.
+pub fn parse_token<T: FromStr>(input: &str, token: &str) -> Result<T, ParseError> {
+    token.parse().map_err(|_| {
+        ParseError::at(
+            input,
+            token,
+            format!("invalid {}", std::any::type_name::<T>()),
+        )
+    })
+}
+
+/// The character starting at byte `idx` of `s`, as a subslice usable with [`ParseError::at`].
+pub fn char_at(s: &str, idx: usize) -> &str {
+    let len = s[idx..].chars().next().map_or(0, char::len_utf8);
+    &s[idx..idx + len]
+}
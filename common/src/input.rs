@@ -0,0 +1,88 @@
+//! Cleaning up puzzle inputs before parsing, so parsers only ever see `\n` line endings.
+
+use crate::ParseError;
+use std::borrow::Cow;
+
+/// `raw` without a leading byte order mark, with `\r\n` and lone `\r` line endings turned into
+/// `\n`, and without trailing blank lines. Non-empty results end with exactly one `\n`.
+pub fn normalize(raw: &str) -> Cow<'_, str> {
+    let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+    // End of the last line with content, keeping its trailing spaces.
+    let end = raw.trim_end().len();
+    let end = end + raw[end..].find(['\n', '\r']).unwrap_or(raw.len() - end);
+    let content = &raw[..end];
+    if content.is_empty() {
+        Cow::Borrowed("")
+    } else if !content.contains('\r') && &raw[end..] == "\n" {
+        Cow::Borrowed(raw)
+    } else {
+        Cow::Owned(content.replace("\r\n", "\n").replace('\r', "\n") + "\n")
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Blocks of `input` separated by one or more blank lines, without their trailing newline.
+/// Each block is a subslice of `input`, so errors in it can be reported with [`ParseError::at`]
+/// against the whole input.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end = 0;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if is_blank(content) {
+            if let Some(start) = start.take() {
+                sections.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(&input[start..end]);
+    }
+    sections
+}
+
+/// The two sections of an input made of exactly two, e.g. a map followed by moves.
+/// `second` names what is missing if there is only one section.
+pub fn two_sections<'a>(input: &'a str, second: &str) -> Result<(&'a str, &'a str), ParseError> {
+    match sections(input)[..] {
+        [first, second] => Ok((first, second)),
+        [_, _, third, ..] => Err(ParseError::at(input, third, "unexpected section")),
+        _ => Err(ParseError::missing(input, second)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_line_endings() {
+        assert_eq!(normalize("a\nb\n"), "a\nb\n");
+        assert!(matches!(normalize("a\nb\n"), Cow::Borrowed(_)));
+        assert_eq!(normalize("\u{feff}a\r\nb\r\n\r\n\n"), "a\nb\n");
+        assert_eq!(normalize("a\rb"), "a\nb\n");
+        assert_eq!(normalize("a \n  \n"), "a \n");
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "\na\nb\n\n \n\nc\n";
+        assert_eq!(sections(input), ["a\nb", "c"]);
+        assert_eq!(sections("a\r\n\r\nb"), ["a", "b"]);
+        assert_eq!(two_sections(input, "c").unwrap(), ("a\nb", "c"));
+        let error = two_sections("a\nb\n", "moves").unwrap_err();
+        assert_eq!(error.message, "missing moves");
+        let error = two_sections("a\n\nb\n\nc", "moves").unwrap_err();
+        assert_eq!((error.line, error.token.as_str()), (5, "c"));
+    }
+}
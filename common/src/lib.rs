@@ -0,0 +1,83 @@
+pub mod diagnostics;
+pub mod error;
+pub mod input;
+pub mod testing;
+
+pub use error::{char_at, parse_token, ParseError};
+use std::fmt::Display;
+
+/// A puzzle solution, split into parsing the input into a typed model and solving both parts on it.
+pub trait Solution {
+    /// Parsed puzzle input, shared by both parts.
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Day 25 only has a single puzzle.
+    const HAS_PART2: bool = true;
+
+    /// Parses the input, which [`parse_input`] has already passed through [`input::normalize`].
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// Type-erased parsed input, so solutions with different models can be handled uniformly.
+pub trait Parsed {
+    fn part1(&self) -> String;
+    /// `None` if the day has no second puzzle.
+    fn part2(&self) -> Option<String>;
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part1(&self) -> String {
+        S::part1(&self.0).to_string()
+    }
+    fn part2(&self) -> Option<String> {
+        S::HAS_PART2.then(|| S::part2(&self.0).to_string())
+    }
+}
+
+/// Normalizes the raw contents of an input file and parses them with `S`.
+/// Every caller of [`Solution::parse`] should go through this.
+pub fn parse_input<S: Solution>(raw: &str) -> Result<S::Input, ParseError> {
+    S::parse(&input::normalize(raw))
+}
+
+/// Parses `input` with `S`, erasing the model type.
+pub fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Parsed>, ParseError>
+where
+    S::Input: 'static,
+{
+    Ok(Box::new(ParsedInput::<S>(parse_input::<S>(input)?)))
+}
+
+/// Entry point of the per-day binaries: solves the file given as first argument,
+/// or `default_input` if there is none.
+pub fn main<S: Solution + 'static>(default_input: &str)
+where
+    S::Input: 'static,
+{
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| default_input.to_string());
+    let input = match std::fs::read_to_string(&path) {
+        Ok(input) => input,
+        Err(e) => exit(&format!("Failed to read {}: {}", path, e)),
+    };
+    let parsed = match parse::<S>(&input) {
+        Ok(parsed) => parsed,
+        Err(e) => exit(&e.with_file(&path)),
+    };
+    println!("Part 1: {}", parsed.part1());
+    if let Some(answer) = parsed.part2() {
+        println!("Part 2: {}", answer);
+    }
+}
+
+fn exit(error: &dyn Display) -> ! {
+    eprintln!("Error: {}", error);
+    std::process::exit(1);
+}
@@ -0,0 +1,33 @@
+//! Helpers for the per-day regression tests.
+
+use crate::{parse_input, Solution};
+use std::fs;
+
+/// Parses `input` with `S`, failing the test with the parse error if it is invalid.
+pub fn parse<S: Solution>(input: &str) -> S::Input {
+    parse_input::<S>(input).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Solves the file `input` with `S` and compares the answers with those recorded in the file
+/// `answers`, which has the same `Part 1: ...` / `Part 2: ...` lines the binaries print.
+/// Only the parts listed in `parts` are solved, so slow ones can be left out.
+pub fn check_answers<S: Solution>(input: &str, answers: &str, parts: &[u8]) {
+    let read = |path: &str| {
+        fs::read_to_string(path).unwrap_or_else(|e| panic!("Failed to read {}: {}", path, e))
+    };
+    let expected = read(answers);
+    let parsed =
+        parse_input::<S>(&read(input)).unwrap_or_else(|e| panic!("{}", e.with_file(input)));
+    for &part in parts {
+        let expected = expected
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("Part {}: ", part)))
+            .unwrap_or_else(|| panic!("{} has no answer for part {}", answers, part));
+        let answer = match part {
+            1 => S::part1(&parsed).to_string(),
+            2 => S::part2(&parsed).to_string(),
+            _ => panic!("invalid part {}", part),
+        };
+        assert_eq!(answer, expected, "part {} of {}", part, input);
+    }
+}
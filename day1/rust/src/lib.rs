@@ -0,0 +1,570 @@
+//! Day 1: Historian Hysteria.
+
+use common::{parse_token, ParseError, Solution};
+use std::collections::BTreeMap;
+use std::io::{self, BufRead};
+use std::ops::Bound;
+
+/// One of the two lists of location IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    /// The list on the other side.
+    pub fn other(self) -> Side {
+        match self {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// The IDs of one list, the smallest of which are paired up with those of the other list.
+#[derive(Debug, Clone, Default)]
+struct List {
+    /// Number of times each ID occurs.
+    counts: BTreeMap<u32, u64>,
+    len: u64,
+    /// Largest paired ID and how many of its copies are paired, all smaller IDs being paired.
+    last_paired: Option<(u32, u64)>,
+}
+
+impl List {
+    fn count(&self, id: u32) -> u64 {
+        self.counts.get(&id).copied().unwrap_or(0)
+    }
+
+    /// The largest ID with all its copies, for a list that is paired up entirely.
+    fn all_paired(&self) -> Option<(u32, u64)> {
+        self.counts
+            .last_key_value()
+            .map(|(&id, &count)| (id, count))
+    }
+
+    /// Pairs up the smallest unpaired ID, which has to exist, and returns it.
+    fn pair_next(&mut self) -> u32 {
+        let next = match self.last_paired {
+            Some((id, paired)) if paired < self.count(id) => (id, paired + 1),
+            Some((id, _)) => {
+                let mut larger = self.counts.range((Bound::Excluded(id), Bound::Unbounded));
+                (*larger.next().expect("an unpaired ID").0, 1)
+            }
+            None => (*self.counts.keys().next().expect("an unpaired ID"), 1),
+        };
+        self.last_paired = Some(next);
+        next.0
+    }
+
+    /// Unpairs the largest paired ID, which has to exist, and returns it.
+    fn unpair_last(&mut self) -> u32 {
+        let (id, paired) = self.last_paired.expect("a paired ID");
+        self.last_paired = match paired {
+            1 => self
+                .counts
+                .range(..id)
+                .next_back()
+                .map(|(&id, &count)| (id, count)),
+            _ => Some((id, paired - 1)),
+        };
+        id
+    }
+}
+
+/// A block of paired IDs of both lists, in order.
+#[derive(Debug, Clone)]
+struct Block {
+    /// IDs with +1 for the left list and -1 for the right one.
+    ids: Vec<(u32, i64)>,
+    /// Sum of the steps of the IDs.
+    rise: i64,
+    /// Levels λ of the terms c·|offset + λ| that make up the block's share of the distance, in
+    /// order, with the sums of c and c·λ over the terms before each one.
+    levels: Vec<i64>,
+    sums: Vec<(i128, i128)>,
+}
+
+impl Block {
+    fn new(ids: Vec<(u32, i64)>) -> Self {
+        let mut level = 0;
+        let mut terms = Vec::with_capacity(2 * ids.len());
+        for &(id, step) in &ids {
+            // the ID adds id·(|level before it| - |level after it|)
+            terms.push((level, id as i128));
+            level += step;
+            terms.push((level, -(id as i128)));
+        }
+        terms.sort_unstable();
+        let mut sums = Vec::with_capacity(terms.len() + 1);
+        let (mut coefficients, mut products) = (0, 0);
+        sums.push((0, 0));
+        for &(level, coefficient) in &terms {
+            coefficients += coefficient;
+            products += coefficient * level as i128;
+            sums.push((coefficients, products));
+        }
+        let levels = terms.into_iter().map(|(level, _)| level).collect();
+        Block {
+            ids,
+            rise: level,
+            levels,
+            sums,
+        }
+    }
+
+    /// Share of the distance of the block when the IDs before it leave the walk at `offset`.
+    fn share(&self, offset: i64) -> i128 {
+        let split = self.levels.partition_point(|&level| level < -offset);
+        let (below, below_products) = self.sums[split];
+        let (total, total_products) = self.sums[self.levels.len()];
+        let offset = offset as i128;
+        let above = offset * (total - below) + total_products - below_products;
+        above - (offset * below + below_products)
+    }
+}
+
+/// The paired IDs of both lists in order, as a walk that goes up for left IDs and down for right
+/// ones. The total distance is the area between the walk and zero: the number of IDs in one list
+/// up to each value minus those in the other one, summed over all values. It is the sum over all
+/// IDs of the ID times how much closer to zero the walk gets at it.
+///
+/// The IDs are kept in blocks of about the square root of their number, each of which can tell
+/// its share for any level the walk enters it at in logarithmic time, so that the distance is
+/// updated in time about the square root of the number of IDs.
+#[derive(Debug, Clone, Default)]
+struct Pairs {
+    blocks: Vec<Block>,
+    len: usize,
+    distance: u64,
+}
+
+impl Pairs {
+    fn block_size(&self) -> usize {
+        self.len.isqrt().max(16)
+    }
+
+    fn insert(&mut self, id: u32, step: i64) {
+        let block = self
+            .blocks
+            .iter()
+            .position(|block| block.ids.last().is_some_and(|&(last, _)| last >= id))
+            .unwrap_or(self.blocks.len().saturating_sub(1));
+        if self.blocks.is_empty() {
+            self.blocks.push(Block::new(Vec::new()));
+        }
+        let mut ids = std::mem::take(&mut self.blocks[block].ids);
+        ids.insert(ids.partition_point(|&(other, _)| other <= id), (id, step));
+        self.len += 1;
+        if ids.len() > 2 * self.block_size() {
+            let second = ids.split_off(ids.len() / 2);
+            self.blocks.insert(block + 1, Block::new(second));
+        }
+        self.blocks[block] = Block::new(ids);
+    }
+
+    fn remove(&mut self, id: u32, step: i64) {
+        let (block, position) = self
+            .blocks
+            .iter()
+            .enumerate()
+            .filter(|(_, block)| block.ids.last().is_some_and(|&(last, _)| last >= id))
+            .find_map(|(idx, block)| {
+                let start = block.ids.partition_point(|&(other, _)| other < id);
+                let mut copies = block.ids[start..]
+                    .iter()
+                    .take_while(|&&(other, _)| other == id);
+                let offset = copies.position(|&(_, other)| other == step)?;
+                Some((idx, start + offset))
+            })
+            .expect("removing a paired ID");
+        let mut ids = std::mem::take(&mut self.blocks[block].ids);
+        ids.remove(position);
+        self.len -= 1;
+        // merge small blocks with the next one, to keep their number in check
+        if let Some(next) = self.blocks.get(block + 1) {
+            if ids.len() + next.ids.len() <= self.block_size() {
+                ids.extend_from_slice(&self.blocks.remove(block + 1).ids);
+            }
+        }
+        if ids.is_empty() {
+            self.blocks.remove(block);
+        } else {
+            self.blocks[block] = Block::new(ids);
+        }
+    }
+
+    /// Recomputes the distance from the shares of the blocks, once both lists have the same
+    /// number of paired IDs again.
+    fn update_distance(&mut self) {
+        let mut offset = 0;
+        let mut distance = 0;
+        for block in &self.blocks {
+            distance += block.share(offset);
+            offset += block.rise;
+        }
+        debug_assert_eq!(offset, 0, "as many left as right IDs are paired");
+        self.distance = u64::try_from(distance).expect("distances are not negative");
+    }
+}
+
+/// Both lists of location IDs, kept sorted as the number of times each ID occurs, so that IDs
+/// can be added and removed on either side at any time.
+///
+/// The similarity score and the total distance are updated with every change, the distance in
+/// time about the square root of the number of IDs.
+#[derive(Debug, Clone, Default)]
+pub struct LocationLists {
+    left: List,
+    right: List,
+    similarity: u64,
+    pairs: Pairs,
+}
+
+fn step(side: Side) -> i64 {
+    match side {
+        Side::Left => 1,
+        Side::Right => -1,
+    }
+}
+
+impl LocationLists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn list(&self, side: Side) -> &List {
+        match side {
+            Side::Left => &self.left,
+            Side::Right => &self.right,
+        }
+    }
+
+    /// The list on `side` and the other one.
+    fn lists_mut(&mut self, side: Side) -> (&mut List, &mut List) {
+        match side {
+            Side::Left => (&mut self.left, &mut self.right),
+            Side::Right => (&mut self.right, &mut self.left),
+        }
+    }
+
+    /// Number of IDs in the list on `side`.
+    pub fn len(&self, side: Side) -> u64 {
+        self.list(side).len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.len == 0 && self.right.len == 0
+    }
+
+    /// Number of times `id` occurs in the list on `side`.
+    pub fn count(&self, side: Side, id: u32) -> u64 {
+        self.list(side).count(id)
+    }
+
+    /// Adds `id` to the list on `side`.
+    pub fn insert(&mut self, side: Side, id: u32) {
+        let (list, other) = self.lists_mut(side);
+        *list.counts.entry(id).or_default() += 1;
+        list.len += 1;
+        let similarity = id as u64 * other.count(id);
+        if list.len <= other.len {
+            // the list is paired up entirely, with one more ID of the other list
+            list.last_paired = list.all_paired();
+            let next = other.pair_next();
+            self.pairs.insert(id, step(side));
+            self.pairs.insert(next, step(side.other()));
+        } else if list.last_paired.is_some_and(|(last, _)| id < last) {
+            // the ID takes the place of the largest paired one
+            let dropped = list.unpair_last();
+            self.pairs.insert(id, step(side));
+            self.pairs.remove(dropped, step(side));
+        }
+        self.similarity += similarity;
+        self.pairs.update_distance();
+    }
+
+    /// Removes one occurrence of `id` from the list on `side`, returning whether there was one.
+    pub fn remove(&mut self, side: Side, id: u32) -> bool {
+        let (list, other) = self.lists_mut(side);
+        let count = list.count(id);
+        if count == 0 {
+            return false;
+        }
+        let similarity = id as u64 * other.count(id);
+        let paired_up = list.len <= other.len;
+        let paired = match list.last_paired {
+            Some((last, paired)) => id < last || (id == last && paired == count),
+            None => false,
+        };
+        if count == 1 {
+            list.counts.remove(&id);
+        } else {
+            list.counts.insert(id, count - 1);
+        }
+        list.len -= 1;
+        if paired_up {
+            // the list stays paired up entirely, with one ID less of the other list
+            list.last_paired = list.all_paired();
+            let dropped = other.unpair_last();
+            self.pairs.remove(id, step(side));
+            self.pairs.remove(dropped, step(side.other()));
+        } else if paired {
+            // the smallest unpaired ID takes the place of the removed one
+            if let Some((last, paired)) = list.last_paired.as_mut() {
+                if *last == id {
+                    *paired -= 1;
+                }
+            }
+            let next = list.pair_next();
+            self.pairs.remove(id, step(side));
+            self.pairs.insert(next, step(side));
+        }
+        self.similarity -= similarity;
+        self.pairs.update_distance();
+        true
+    }
+
+    /// Adds a line of the puzzle input: `left` to the left list and `right` to the right one.
+    pub fn push(&mut self, left: u32, right: u32) {
+        self.insert(Side::Left, left);
+        self.insert(Side::Right, right);
+    }
+
+    /// Sum of the distances between the smallest IDs of both lists, the second smallest, and so
+    /// on. IDs without a counterpart in a shorter list are ignored.
+    pub fn distance(&self) -> u64 {
+        self.pairs.distance
+    }
+
+    /// Sum of each ID in the left list times the number of times it occurs in the right list.
+    pub fn similarity(&self) -> u64 {
+        self.similarity
+    }
+
+    /// Reads one pair of IDs per line from `reader`, without holding more than a line of the
+    /// input in memory. Blank lines and a byte order mark at the start are skipped. Malformed
+    /// lines are reported as [`io::ErrorKind::InvalidData`] errors wrapping a [`ParseError`].
+    pub fn from_reader(mut reader: impl BufRead, columns: Columns) -> io::Result<Self> {
+        let mut lists = LocationLists::new();
+        let mut line = String::new();
+        let mut number = 0;
+        while reader.read_line(&mut line)? > 0 {
+            number += 1;
+            let mut content = line.trim_end_matches(['\n', '\r']);
+            if number == 1 {
+                content = content.strip_prefix('\u{feff}').unwrap_or(content);
+            }
+            if !content.trim().is_empty() {
+                let (left, right) = parse_pair(content, columns).map_err(|mut e| {
+                    e.line = number;
+                    io::Error::new(io::ErrorKind::InvalidData, e)
+                })?;
+                lists.push(left, right);
+            }
+            line.clear();
+        }
+        Ok(lists)
+    }
+}
+
+impl FromIterator<(u32, u32)> for LocationLists {
+    fn from_iter<T: IntoIterator<Item = (u32, u32)>>(pairs: T) -> Self {
+        let mut lists = LocationLists::new();
+        for (left, right) in pairs {
+            lists.push(left, right);
+        }
+        lists
+    }
+}
+
+/// Which whitespace-separated columns of a line hold the left and the right ID, counted from 0.
+/// Other columns are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Columns {
+    pub left: usize,
+    pub right: usize,
+}
+
+impl Default for Columns {
+    /// The first two columns, as in the puzzle input.
+    fn default() -> Self {
+        Columns { left: 0, right: 1 }
+    }
+}
+
+/// Parses the IDs in `columns` of a line, reporting errors relative to `line`.
+fn parse_pair(line: &str, columns: Columns) -> Result<(u32, u32), ParseError> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    let needed = columns.left.max(columns.right) + 1;
+    if fields.len() < needed {
+        return Err(ParseError::at(
+            line,
+            line,
+            format!("expected {} columns, found {}", needed, fields.len()),
+        ));
+    }
+    Ok((
+        parse_token(line, fields[columns.left])?,
+        parse_token(line, fields[columns.right])?,
+    ))
+}
+
+/// Parses one pair of IDs per line from the given `columns`, separated by any whitespace, with
+/// [`LocationLists::from_reader`]. Errors carry the line number within `input`.
+pub fn parse_columns(input: &str, columns: Columns) -> Result<LocationLists, ParseError> {
+    LocationLists::from_reader(input.as_bytes(), columns).map_err(|e| {
+        *e.into_inner()
+            .and_then(|e| e.downcast::<ParseError>().ok())
+            .expect("reading from memory only fails on malformed lines")
+    })
+}
+
+/// Part 1 sums the distances between the sorted lists, part 2 their similarity score.
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = LocationLists;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_columns(input, Columns::default())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.distance()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.similarity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Distance and similarity of plain lists, computed from scratch.
+    fn batch(left: &[u32], right: &[u32]) -> (u64, u64) {
+        let (mut left, mut right) = (left.to_vec(), right.to_vec());
+        left.sort();
+        right.sort();
+        let distance = left
+            .iter()
+            .zip(&right)
+            .map(|(l, r)| l.abs_diff(*r) as u64)
+            .sum();
+        let mut counter: HashMap<u32, u64> = HashMap::new();
+        for &id in &right {
+            *counter.entry(id).or_default() += 1;
+        }
+        let similarity = left
+            .iter()
+            .map(|id| *id as u64 * counter.get(id).unwrap_or(&0))
+            .sum();
+        (distance, similarity)
+    }
+
+    #[test]
+    fn updates_online() {
+        let mut lists = LocationLists::new();
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        // a fixed pseudo-random mix of insertions and removals on both sides
+        let mut state = 12345u32;
+        for _ in 0..2000 {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            let id = (state >> 16) % 20;
+            let (side, list) = match state % 2 {
+                0 => (Side::Left, &mut left),
+                _ => (Side::Right, &mut right),
+            };
+            if state % 7 < 2 {
+                let position = list.iter().position(|&x| x == id);
+                assert_eq!(lists.remove(side, id), position.is_some());
+                if let Some(position) = position {
+                    list.remove(position);
+                }
+            } else {
+                lists.insert(side, id);
+                list.push(id);
+            }
+            assert_eq!((lists.distance(), lists.similarity()), batch(&left, &right));
+        }
+        assert_eq!(lists.len(Side::Left), left.len() as u64);
+    }
+
+    #[test]
+    fn keeps_distance_through_blocks() {
+        let mut lists = LocationLists::new();
+        let (mut left, mut right) = (Vec::new(), Vec::new());
+        let mut state = 987654321u64;
+        let mut next = move || {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            state >> 33
+        };
+        for round in 0..3000 {
+            let id = match next() % 50 {
+                0 => u32::MAX,
+                1 => 0,
+                number => number as u32 * 1000 + next() as u32 % 1000,
+            };
+            let (side, list) = match next() % 2 {
+                0 => (Side::Left, &mut left),
+                _ => (Side::Right, &mut right),
+            };
+            // grow the lists at first, then shrink them again
+            if round > 1500 && next() % 4 != 0 && !list.is_empty() {
+                let id = list.swap_remove(next() as usize % list.len());
+                assert!(lists.remove(side, id));
+            } else {
+                lists.insert(side, id);
+                list.push(id);
+            }
+            if round % 25 == 0 {
+                assert_eq!(lists.distance(), batch(&left, &right).0);
+            }
+        }
+        assert_eq!(lists.distance(), batch(&left, &right).0);
+    }
+
+    #[test]
+    fn reads_streams() {
+        let input = "\u{feff}3   4\r\n4   3\r\n\r\n";
+        let lists = LocationLists::from_reader(input.as_bytes(), Columns::default()).unwrap();
+        assert_eq!((lists.distance(), lists.similarity()), (0, 7));
+        let input = "3   4\n4   x\n".as_bytes();
+        let error = LocationLists::from_reader(input, Columns::default()).unwrap_err();
+        let error = error
+            .into_inner()
+            .unwrap()
+            .downcast::<ParseError>()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+        let input = "3   4\n\u{feff}4   3\n".as_bytes();
+        let error = LocationLists::from_reader(input, Columns::default()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn parses_any_columns() {
+        let input = "a\t3 4   9\nb 4\t\t3  1\n\nc  2 5 1\n";
+        let columns = Columns { left: 2, right: 1 };
+        let lists = parse_columns(input, columns).unwrap();
+        assert_eq!(lists.len(Side::Left), 3);
+        assert_eq!(lists.count(Side::Right, 3), 1);
+        assert_eq!((lists.distance(), lists.similarity()), (3, 7));
+        let error = parse_columns(input, Columns { left: 0, right: 1 }).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (1, 1, "a")
+        );
+        let error = parse_columns("3 4\n5\n", Columns::default()).unwrap_err();
+        assert_eq!(
+            (error.line, error.message.as_str()),
+            (2, "expected 2 columns, found 1")
+        );
+    }
+}
@@ -1,40 +1,50 @@
-use std::collections::HashMap;
+use common::ParseError;
+use day1::{Columns, Day1, LocationLists};
+use std::fs::File;
+use std::io::BufReader;
 
-fn part1(left_list: &[u32], right_list: &[u32]) -> u32 {
-    let mut left_list = left_list.to_vec();
-    let mut right_list = right_list.to_vec();
-    left_list.sort();
-    right_list.sort();
-    left_list
-        .iter()
-        .zip(right_list.iter())
-        .map(|(left, right)| left.abs_diff(*right))
-        .sum()
-}
+const DEFAULT_INPUT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../locations.txt");
 
-fn part2(left_list: &[u32], right_list: &[u32]) -> u32 {
-    let mut counter = HashMap::new();
-    for &num in right_list.iter() {
-        *counter.entry(num).or_insert(0) += 1;
+/// Parses `L,R` into the columns to read the left and the right IDs from, counted from 0.
+fn parse_columns(spec: &str) -> Result<Columns, String> {
+    let parsed = spec
+        .split_once(',')
+        .and_then(|(left, right)| Some((left.parse().ok()?, right.parse().ok()?)));
+    match parsed {
+        Some((left, right)) => Ok(Columns { left, right }),
+        None => Err(format!("expected columns as 'L,R', got '{}'", spec)),
     }
-    left_list
-        .iter()
-        .map(|num| counter.get(num).unwrap_or(&0) * num)
-        .sum()
+}
+
+/// Solves the file at `path`, reading the IDs from `columns` line by line.
+fn solve(columns: Columns, path: &str) -> Result<(), String> {
+    let file = File::open(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let lists = LocationLists::from_reader(BufReader::new(file), columns).map_err(|e| {
+        match e
+            .get_ref()
+            .and_then(|inner| inner.downcast_ref::<ParseError>())
+        {
+            Some(error) => error.clone().with_file(path).to_string(),
+            None => format!("Failed to read {}: {}", path, e),
+        }
+    })?;
+    println!("Part 1: {}", lists.distance());
+    println!("Part 2: {}", lists.similarity());
+    Ok(())
 }
 
 fn main() {
-    let (left_list, right_list): (Vec<u32>, Vec<u32>) = std::fs::read_to_string("../locations.txt")
-        .unwrap()
-        .lines()
-        .map(|line| {
-            let parts: Vec<&str> = line.split("   ").collect();
-            (
-                parts[0].parse::<u32>().unwrap(),
-                parts[1].parse::<u32>().unwrap(),
-            )
-        })
-        .unzip();
-    println!("Part 1: {}", part1(&left_list, &right_list));
-    println!("Part 2: {}", part2(&left_list, &right_list));
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) != Some("--columns") {
+        return common::main::<Day1>(DEFAULT_INPUT);
+    }
+    let result = match args.get(1) {
+        Some(spec) => parse_columns(spec)
+            .and_then(|columns| solve(columns, args.get(2).map_or(DEFAULT_INPUT, String::as_str))),
+        None => Err("Usage: day1 --columns L,R [INPUT]".to_string()),
+    };
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
 }
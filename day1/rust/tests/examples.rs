@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day1::Day1;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day1>(EXAMPLE);
+    assert_eq!(Day1::part1(&input), 11);
+    assert_eq!(Day1::part2(&input), 31);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day1>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../locations.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
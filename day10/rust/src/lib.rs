@@ -0,0 +1,69 @@
+//! Day 10: Hoof It.
+
+use common::{ParseError, Solution};
+use grid::{Coords, Grid};
+use search::{bfs, Paths};
+
+/// Searches uphill from `trailhead`. Every step climbs by one, so all trails to a summit are
+/// equally long and the search keeps every one of them.
+fn trails(map: &Grid<u32>, trailhead: Coords) -> Paths<Coords, usize> {
+    bfs(
+        trailhead,
+        |&pos| {
+            map.neighbors4(pos)
+                .filter(move |&next| map[next] == map[pos] + 1)
+        },
+        |_| false,
+    )
+}
+
+/// Sum over the trailheads (height 0) of the number of summits (height 9) each one reaches.
+pub fn trailhead_scores(map: &Grid<u32>) -> u32 {
+    map.find_all(&0)
+        .map(|trailhead| {
+            trails(map, trailhead)
+                .reached()
+                .filter(|&&pos| map[pos] == 9)
+                .count() as u32
+        })
+        .sum()
+}
+
+/// Sum over the trailheads of the number of distinct trails from each one to any summit.
+pub fn trailhead_ratings(map: &Grid<u32>) -> u32 {
+    map.find_all(&0)
+        .map(|trailhead| {
+            let trails = trails(map, trailhead);
+            trails
+                .reached()
+                .filter(|&&pos| map[pos] == 9)
+                .map(|summit| trails.count_paths_to(summit) as u32)
+                .sum::<u32>()
+        })
+        .sum()
+}
+
+fn parse_map(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse_with(input, |c| c.to_digit(10))
+}
+
+/// The input is the topographic map with one digit height per tile.
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        trailhead_scores(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        trailhead_ratings(input)
+    }
+}
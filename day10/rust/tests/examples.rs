@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day10::Day10;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day10>(EXAMPLE);
+    assert_eq!(Day10::part1(&input), 36);
+    assert_eq!(Day10::part2(&input), 81);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day10>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../map.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,68 @@
+//! Day 11: Plutonian Pebbles.
+
+use common::{parse_token, ParseError, Solution};
+use std::collections::HashMap;
+
+type StoneBlinkPair = (u64, u64);
+type Cache = HashMap<StoneBlinkPair, u64>;
+
+fn blink(stone: u64, blinks_left: u64, cache: &mut Cache) -> u64 {
+    if let Some(&result) = cache.get(&(stone, blinks_left)) {
+        return result;
+    }
+
+    if blinks_left == 0 {
+        return 1;
+    }
+    if stone == 0 {
+        let result = blink(1, blinks_left - 1, cache);
+        cache.insert((stone, blinks_left), result);
+        return result;
+    }
+    let stone_string = stone.to_string();
+    if stone_string.len().is_multiple_of(2) {
+        let (left, right) = stone_string.split_at(stone_string.len() / 2);
+        let left: u64 = left.parse().unwrap();
+        let right: u64 = right.parse().unwrap();
+        let result = blink(left, blinks_left - 1, cache) + blink(right, blinks_left - 1, cache);
+        cache.insert((stone, blinks_left), result);
+        return result;
+    }
+    let result = blink(stone * 2024, blinks_left - 1, cache);
+    cache.insert((stone, blinks_left), result);
+    result
+}
+
+/// Number of stones after blinking `blinks` times.
+pub fn stones_after(stones: &[u64], blinks: u64) -> u64 {
+    let mut cache = Cache::new();
+    stones.iter().map(|x| blink(*x, blinks, &mut cache)).sum()
+}
+
+fn parse_stones(input: &str) -> Result<Vec<u64>, ParseError> {
+    input
+        .split_whitespace()
+        .map(|x| parse_token(input, x))
+        .collect()
+}
+
+/// Part 1 counts the stones after 25 blinks, part 2 after 75.
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_stones(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        stones_after(input, 25)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        stones_after(input, 75)
+    }
+}
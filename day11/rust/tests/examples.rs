@@ -0,0 +1,20 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day11::Day11;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day11>(EXAMPLE);
+    assert_eq!(Day11::part1(&input), 55312);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day11>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,125 @@
+//! Day 12: Garden Groups.
+
+use common::{ParseError, Solution};
+use grid::{Coords, Direction, Grid};
+use search::bfs;
+use std::collections::{HashMap, HashSet};
+
+/// The garden, with the plant type of each plot.
+pub type Plots = Grid<char>;
+
+/// Plots connected to `root` through edges shared with plots of the same label.
+fn region(plots: &Plots, root: Coords) -> Vec<Coords> {
+    let label = plots[root];
+    bfs(
+        root,
+        |&pos| {
+            plots
+                .neighbors4(pos)
+                .filter(move |&next| plots[next] == label)
+        },
+        |_| false,
+    )
+    .reached()
+    .copied()
+    .collect()
+}
+
+/// All regions of the garden, each listed once.
+pub fn regions(plots: &Plots) -> Vec<Vec<Coords>> {
+    let mut seen = Grid::filled(plots.height, plots.width, false);
+    let mut regions = Vec::new();
+    for root in plots.coords() {
+        if seen[root] {
+            continue;
+        }
+        let region = region(plots, root);
+        region.iter().for_each(|&pos| seen[pos] = true);
+        regions.push(region);
+    }
+    regions
+}
+
+/// Total price of fencing every region, where a region costs its area times its perimeter.
+pub fn fence_price(plots: &Plots) -> usize {
+    regions(plots)
+        .iter()
+        .map(|region| {
+            let label = plots[region[0]];
+            let perimeter: usize = region
+                .iter()
+                .map(|&pos| 4 - plots.neighbors4(pos).filter(|&n| plots[n] == label).count())
+                .sum();
+            region.len() * perimeter
+        })
+        .sum()
+}
+
+/// Number of groups of adjacent cells among `vertices`.
+fn graph_components(plots: &Plots, vertices: &HashSet<Coords>) -> usize {
+    let mut seen: HashSet<Coords> = HashSet::new();
+    let mut num_components: usize = 0;
+    for &root in vertices {
+        if seen.contains(&root) {
+            continue;
+        }
+        num_components += 1;
+        let component = bfs(
+            root,
+            |&pos| plots.neighbors4(pos).filter(|next| vertices.contains(next)),
+            |_| false,
+        );
+        seen.extend(component.reached());
+    }
+    num_components
+}
+
+/// Total price with the bulk discount, where a region costs its area times its number of
+/// straight sides.
+pub fn bulk_fence_price(plots: &Plots) -> usize {
+    regions(plots)
+        .iter()
+        .map(|region| {
+            let label = plots[region[0]];
+            // Cells of the region bordering something else, by the side the border is on.
+            let mut edges: HashMap<Direction, HashSet<Coords>> = HashMap::new();
+            for &pos in region {
+                for direction in Direction::ALL {
+                    if plots.walk(pos, direction).map(|next| plots[next]) != Some(label) {
+                        edges.entry(direction).or_default().insert(pos);
+                    }
+                }
+            }
+            let sides: usize = edges
+                .values()
+                .map(|d_edges| graph_components(plots, d_edges))
+                .sum();
+            region.len() * sides
+        })
+        .sum()
+}
+
+fn parse_plots(input: &str) -> Result<Plots, ParseError> {
+    Grid::parse(input)
+}
+
+/// The input is the garden map; the answers are the fence prices without and with discount.
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Plots;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_plots(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        fence_price(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        bulk_fence_price(input)
+    }
+}
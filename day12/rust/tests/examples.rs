@@ -0,0 +1,29 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day12::Day12;
+
+const SMALL_EXAMPLE: &str = include_str!("../../test_plots.txt");
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn small_example() {
+    let input = parse::<Day12>(SMALL_EXAMPLE);
+    assert_eq!(Day12::part1(&input), 140);
+    assert_eq!(Day12::part2(&input), 80);
+}
+
+#[test]
+fn example() {
+    let input = parse::<Day12>(EXAMPLE);
+    assert_eq!(Day12::part1(&input), 1930);
+    assert_eq!(Day12::part2(&input), 1206);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day12>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../plots.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
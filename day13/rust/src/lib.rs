@@ -0,0 +1,170 @@
+//! Day 13: Claw Contraption.
+
+use common::{parse_token, ParseError, Solution};
+use regex::RegexBuilder;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Point {
+    pub x: u64,
+    pub y: u64,
+}
+
+/// A claw machine: how far each button moves the claw, and where the prize is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Arcade {
+    pub button_a: Point,
+    pub button_b: Point,
+    pub prize: Point,
+}
+
+impl Arcade {
+    /// Fewest tokens to win the prize, where pressing A costs 3 tokens and B costs 1.
+    pub fn cheapest_win(&self) -> Option<u64> {
+        math_optimum_arcade_prize(self)
+    }
+
+    /// The same machine with the prize moved by `offset` along both axes.
+    pub fn with_prize_offset(&self, offset: u64) -> Self {
+        Arcade {
+            prize: Point {
+                x: self.prize.x + offset,
+                y: self.prize.y + offset,
+            },
+            ..*self
+        }
+    }
+}
+
+fn math_optimum_arcade_prize(arcade: &Arcade) -> Option<u64> {
+    let x11 = arcade.button_a.x as f64;
+    let x21 = arcade.button_a.y as f64;
+    let x12 = arcade.button_b.x as f64;
+    let x22 = arcade.button_b.y as f64;
+
+    let prize_x = arcade.prize.x as f64;
+    let prize_y = arcade.prize.y as f64;
+
+    let det = x11 * x22 - x12 * x21;
+    let a = (x22 * prize_x - x12 * prize_y) / det;
+    let b = (x11 * prize_y - x21 * prize_x) / det;
+
+    if a.fract() == 0.0 && b.fract() == 0.0 && a >= 0.0 && b >= 0.0 {
+        return Some(3 * a as u64 + b as u64);
+    }
+    None
+}
+
+fn part1(arcades: &[Arcade]) -> u64 {
+    arcades.iter().filter_map(Arcade::cheapest_win).sum()
+}
+fn part2(arcades: &[Arcade]) -> u64 {
+    let arcades: Vec<Arcade> = arcades
+        .iter()
+        .map(|a| a.with_prize_offset(10000000000000))
+        .collect();
+    part1(&arcades)
+}
+
+fn parse_arcades(input: &str) -> Result<Vec<Arcade>, ParseError> {
+    let arcade_regex = RegexBuilder::new(
+        r"Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)",
+    )
+    .multi_line(true)
+    .build()
+    .unwrap();
+    arcade_regex
+        .captures_iter(input)
+        .map(|c| {
+            Ok(Arcade {
+                button_a: Point {
+                    x: parse_token(input, &c[1])?,
+                    y: parse_token(input, &c[2])?,
+                },
+                button_b: Point {
+                    x: parse_token(input, &c[3])?,
+                    y: parse_token(input, &c[4])?,
+                },
+                prize: Point {
+                    x: parse_token(input, &c[5])?,
+                    y: parse_token(input, &c[6])?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// Part 1 spends the fewest tokens on the machines as given, part 2 with the prizes moved far away.
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Arcade>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_arcades(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Cheapest win found by trying every number of A presses.
+    fn brute_force_cheapest_win(arcade: &Arcade) -> Option<u64> {
+        let Arcade {
+            button_a: a,
+            button_b: b,
+            prize,
+        } = arcade;
+        (0..=prize.x / a.x)
+            .filter_map(|presses_a| {
+                let rest_x = prize.x - presses_a * a.x;
+                let presses_b = rest_x / b.x;
+                (rest_x % b.x == 0 && presses_a * a.y + presses_b * b.y == prize.y)
+                    .then_some(3 * presses_a + presses_b)
+            })
+            .min()
+    }
+
+    fn points(range: std::ops::Range<u64>) -> impl Strategy<Value = Point> {
+        (range.clone(), range).prop_map(|(x, y)| Point { x, y })
+    }
+
+    /// Machines with non-parallel buttons, and prizes that are either reachable within 100
+    /// presses of each button or anywhere.
+    fn arcades() -> impl Strategy<Value = Arcade> {
+        (points(1..100), points(1..100))
+            .prop_filter("buttons move in parallel", |(a, b)| a.x * b.y != a.y * b.x)
+            .prop_flat_map(|(button_a, button_b)| {
+                let reachable =
+                    (0u64..=100, 0u64..=100).prop_map(move |(presses_a, presses_b)| Point {
+                        x: presses_a * button_a.x + presses_b * button_b.x,
+                        y: presses_a * button_a.y + presses_b * button_b.y,
+                    });
+                prop_oneof![reachable, points(0..20_000)].prop_map(move |prize| Arcade {
+                    button_a,
+                    button_b,
+                    prize,
+                })
+            })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(4000))]
+
+        #[test]
+        fn determinant_matches_brute_force(arcade in arcades()) {
+            prop_assert_eq!(arcade.cheapest_win(), brute_force_cheapest_win(&arcade));
+        }
+    }
+}
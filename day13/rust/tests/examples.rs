@@ -0,0 +1,20 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day13::Day13;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day13>(EXAMPLE);
+    assert_eq!(Day13::part1(&input), 480);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day13>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../arcade.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,246 @@
+//! Day 14: Restroom Redoubt.
+
+use common::{parse_token, ParseError, Solution};
+use grid::Point;
+use ndarray::prelude::*;
+use rayon::prelude::*;
+use render::{Canvas, Cell, Frames};
+use std::error::Error;
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: Array1<i64>,
+    velocity: Array1<i64>,
+}
+
+/// Whether at least `threshold` robots stand on tiles connected through their edges. Tiles
+/// hold robots if `occupied` is `time` for them; `visited` is set to `time` for tiles already
+/// counted, so neither needs clearing between seconds.
+fn has_group(
+    positions: &[[i64; 4]],
+    (height, width): (i64, i64),
+    time: i64,
+    occupied: &[i64],
+    visited: &mut [i64],
+    threshold: usize,
+) -> bool {
+    let index = |x: i64, y: i64| (y * width + x) as usize;
+    let mut stack = Vec::new();
+    for &[x, y, ..] in positions {
+        if visited[index(x, y)] == time {
+            continue;
+        }
+        visited[index(x, y)] = time;
+        stack.push((x, y));
+        let mut size = 0;
+        while let Some((x, y)) = stack.pop() {
+            size += 1;
+            if size >= threshold {
+                return true;
+            }
+            for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+                if (0..width).contains(&nx)
+                    && (0..height).contains(&ny)
+                    && occupied[index(nx, ny)] == time
+                    && visited[index(nx, ny)] != time
+                {
+                    visited[index(nx, ny)] = time;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+    }
+    false
+}
+
+fn predict_robots(robots: &[Robot], height: i64, width: i64, time: i64) -> Vec<Robot> {
+    robots
+        .par_iter()
+        .map(|robot| {
+            (
+                (&robot.position + &robot.velocity * time) % array![width, height],
+                robot,
+            )
+        })
+        .map(|(vec, robot)| Robot {
+            position: array![
+                if vec[0] >= 0 { vec[0] } else { vec[0] + width },
+                if vec[1] >= 0 { vec[1] } else { vec[1] + height }
+            ],
+            velocity: robot.velocity.clone(),
+        })
+        .collect()
+}
+
+fn part1(robots: &[Robot], height: i64, width: i64) -> i64 {
+    predict_robots(robots, height, width, 100)
+        .iter()
+        .fold(vec![0, 0, 0, 0], |mut acc: Vec<i64>, robot| {
+            let x = robot.position[0];
+            let y = robot.position[1];
+            if x < width / 2 && y < height / 2 {
+                acc[0] += 1;
+            } else if x > width / 2 && y < height / 2 {
+                acc[1] += 1;
+            } else if x < width / 2 && y > height / 2 {
+                acc[2] += 1;
+            } else if x > width / 2 && y > height / 2 {
+                acc[3] += 1;
+            }
+            acc
+        })
+        .iter()
+        .product()
+}
+
+/// The floor with a `#` on each tile holding at least one robot.
+pub fn draw_robots(robots: &[Robot], height: i64, width: i64) -> Canvas {
+    let mut canvas = Canvas::new(height as usize, width as usize);
+    let positions = robots
+        .iter()
+        .map(|robot| Point::new(robot.position[0] as isize, robot.position[1] as isize));
+    canvas.overlay(
+        positions,
+        Cell {
+            glyph: '#',
+            ..Cell::ROBOT
+        },
+    );
+    canvas
+}
+
+/// Saves the floor after each of the first `seconds` seconds as a frame.
+pub fn record(robots: &Robots, seconds: usize, frames: &mut Frames) -> Result<(), Box<dyn Error>> {
+    let mut current_robots = robots.robots.clone();
+    for _ in 0..seconds {
+        current_robots = predict_robots(&current_robots, robots.height, robots.width, 1);
+        frames.push(&draw_robots(&current_robots, robots.height, robots.width))?;
+    }
+    Ok(())
+}
+
+/// The first second at which the robots form the tree, a group of at least 25 connected robots,
+/// with their positions at that time. Positions repeat after `height * width` seconds, so if
+/// there is no tree by then there never is one.
+fn find_tree(robots: &[Robot], height: i64, width: i64) -> Option<(i64, Vec<Robot>)> {
+    // x, y, dx, dy of each robot
+    let mut positions: Vec<[i64; 4]> = robots
+        .iter()
+        .map(|robot| {
+            let (position, velocity) = (&robot.position, &robot.velocity);
+            [position[0], position[1], velocity[0], velocity[1]]
+        })
+        .collect();
+    let mut occupied = vec![0; (height * width) as usize];
+    let mut visited = vec![0; (height * width) as usize];
+    for time in 1..=height * width {
+        for [x, y, dx, dy] in positions.iter_mut() {
+            *x = (*x + *dx).rem_euclid(width);
+            *y = (*y + *dy).rem_euclid(height);
+            occupied[(*y * width + *x) as usize] = time;
+        }
+        if has_group(
+            &positions,
+            (height, width),
+            time,
+            &occupied,
+            &mut visited,
+            25,
+        ) {
+            return Some((time, predict_robots(robots, height, width, time)));
+        }
+    }
+    None
+}
+
+/// The first second at which the robots form the tree, if they ever do.
+pub fn tree_time(robots: &Robots) -> Option<i64> {
+    find_tree(&robots.robots, robots.height, robots.width).map(|(time, _)| time)
+}
+
+/// The floor at the first second the robots form the tree, if they ever do.
+pub fn draw_tree(robots: &Robots) -> Option<Canvas> {
+    let (_, tree) = find_tree(&robots.robots, robots.height, robots.width)?;
+    Some(draw_robots(&tree, robots.height, robots.width))
+}
+
+/// Splits `X,Y` at the comma.
+fn pair<'a>(input: &str, pair: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    pair.split_once(',')
+        .ok_or_else(|| ParseError::at(input, pair, "expected 'X,Y'"))
+}
+
+/// Parses one robot per line as `p=X,Y v=DX,DY`, where the position can't be negative.
+fn parse_robots(input: &str) -> Result<Vec<Robot>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (position, velocity) = line
+                .strip_prefix("p=")
+                .and_then(|rest| rest.split_once(" v="))
+                .ok_or_else(|| ParseError::at(input, line, "expected 'p=X,Y v=DX,DY'"))?;
+            let (x, y) = pair(input, position)?;
+            let (dx, dy) = pair(input, velocity)?;
+            Ok(Robot {
+                position: array![
+                    parse_token::<u32>(input, x)? as i64,
+                    parse_token::<u32>(input, y)? as i64
+                ],
+                velocity: array![parse_token(input, dx)?, parse_token(input, dy)?],
+            })
+        })
+        .collect()
+}
+
+/// Robots on a floor of the given size, which differs between the example and the real input.
+#[derive(Debug)]
+pub struct Robots {
+    pub robots: Vec<Robot>,
+    pub height: i64,
+    pub width: i64,
+}
+
+/// Floor sizes as (height, width): the example's and then the real input's.
+const FLOORS: [(i64, i64); 2] = [(7, 11), (103, 101)];
+
+impl Robots {
+    /// Robots on the example's floor if they all start on it, and otherwise on the real one.
+    pub fn new(robots: Vec<Robot>) -> Self {
+        let fits = |&(height, width): &(i64, i64)| {
+            robots
+                .iter()
+                .all(|robot| robot.position[0] < width && robot.position[1] < height)
+        };
+        let (height, width) = FLOORS
+            .into_iter()
+            .find(fits)
+            .unwrap_or(FLOORS[FLOORS.len() - 1]);
+        Robots {
+            robots,
+            height,
+            width,
+        }
+    }
+}
+
+/// Part 1 multiplies the robots per quadrant after 100 seconds, part 2 finds when they form a
+/// tree, or `none` if they never do.
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Robots;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Robots::new(parse_robots(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(&input.robots, input.height, input.width)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        tree_time(input).map_or_else(|| "none".to_string(), |time| time.to_string())
+    }
+}
@@ -0,0 +1,59 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day14::{record, Day14};
+use render::{Format, Frames};
+
+const EXAMPLE: &str = include_str!("../../val_robots.txt");
+
+#[test]
+fn example() {
+    // The example robots all start in the example's smaller room, so they stay in it.
+    let input = parse::<Day14>(EXAMPLE);
+    assert_eq!((input.height, input.width), (7, 11));
+    assert_eq!(Day14::part1(&input), 12);
+    // 12 robots are too few for a tree, and after 77 seconds they are back where they started.
+    assert_eq!(Day14::part2(&input), "none");
+}
+
+#[test]
+fn rejects_malformed_robots() {
+    let error = Day14::parse("p=0,4 v=3,-3\nxx garbage\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.token.as_str()),
+        (2, 1, "xx garbage")
+    );
+    let error = Day14::parse("p=0,4 v=3,-3\np=1,4 v=3;-3\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.token.as_str()),
+        (2, 9, "3;-3")
+    );
+    let error = Day14::parse("p=-1,4 v=3,-3\n").unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.token.as_str()),
+        (1, 3, "-1")
+    );
+}
+
+#[test]
+fn records_frames() {
+    let input = parse::<Day14>(EXAMPLE);
+    let dir = std::env::temp_dir().join(format!("day14-frames-{}", std::process::id()));
+    let mut frames = Frames::new(&dir, Format::Ppm, 1).unwrap();
+    record(&input, 3, &mut frames).unwrap();
+    assert_eq!(frames.len(), 3);
+    for frame in ["frame_00000.ppm", "frame_00001.ppm", "frame_00002.ppm"] {
+        let ppm = std::fs::read(dir.join(frame)).unwrap();
+        assert!(ppm.starts_with(b"P6\n11 7\n255\n"));
+    }
+    assert!(!dir.join("frame_00003.ppm").exists());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day14>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../robots.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
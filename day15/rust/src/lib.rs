@@ -0,0 +1,267 @@
+//! Day 15: Warehouse Woes.
+
+use common::input::two_sections;
+use common::{char_at, ParseError, Solution};
+use grid::{Direction, Grid, Point};
+use render::{Canvas, Cell, Color};
+use std::cmp::PartialEq;
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entity {
+    Wall,
+    Robot,
+    Box,
+}
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Entity2 {
+    Wall,
+    Robot,
+    BoxLeft,
+    BoxRight,
+}
+
+// The warehouse is surrounded by walls, so moving never leaves the grid.
+type Map = Grid<Option<Entity>>;
+type Map2 = Grid<Option<Entity2>>;
+
+fn map_move<T: Copy>(map: &mut Grid<Option<T>>, coords: &Point, dir: &Direction) {
+    let entity = map[*coords].take();
+    map[*coords + *dir] = entity;
+}
+
+fn apply_map_moves<T: Copy>(map: &mut Grid<Option<T>>, coords: &HashSet<Point>, dir: &Direction) {
+    let new_coords: Vec<(Point, Option<T>)> = coords.iter().map(|c| (*c + *dir, map[*c])).collect();
+    coords.iter().for_each(|c| {
+        map[*c] = None;
+    });
+    new_coords.iter().for_each(|(c, e)| {
+        map[*c] = *e;
+    });
+}
+
+fn move_box(map: &mut Map, box_coords: &Point, dir: &Direction) -> bool {
+    let next = *box_coords + *dir;
+
+    match map[next] {
+        Some(Entity::Wall) => false,
+        Some(Entity::Robot) => panic!("how even"),
+        None => {
+            map_move(map, box_coords, dir);
+            true
+        }
+        Some(Entity::Box) => {
+            if move_box(map, &next, dir) {
+                map_move(map, box_coords, dir);
+                return true;
+            }
+            false
+        }
+    }
+}
+
+/// Warehouse layout and the moves the robot attempts.
+pub struct Warehouse {
+    map: Map,
+    robot: Point,
+    moves: Vec<Direction>,
+}
+
+/// `map_str` has to be at the start of `input`, so error positions line up.
+fn parse_map(map_str: &str) -> Result<(Map, Point), ParseError> {
+    let map = Grid::parse_with(map_str, |c| match c {
+        '#' => Some(Some(Entity::Wall)),
+        'O' => Some(Some(Entity::Box)),
+        '@' => Some(Some(Entity::Robot)),
+        '.' => Some(None),
+        _ => None,
+    })?;
+    let robot = map
+        .find(&Some(Entity::Robot))
+        .map(Point::from)
+        .ok_or_else(|| ParseError::missing(map_str, "robot '@'"))?;
+    Ok((map, robot))
+}
+
+fn parse_directions(input: &str, moves: &str) -> Result<Vec<Direction>, ParseError> {
+    moves
+        .lines()
+        .flat_map(|l| {
+            l.char_indices().map(|(idx, c)| {
+                Direction::from_arrow(c)
+                    .ok_or_else(|| ParseError::at(input, char_at(l, idx), "invalid move"))
+            })
+        })
+        .collect()
+}
+
+fn parse_warehouse(input: &str) -> Result<Warehouse, ParseError> {
+    let (map_str, moves) = two_sections(input, "moves after the map")?;
+    let (map, robot) = parse_map(map_str)?;
+    Ok(Warehouse {
+        map,
+        robot,
+        moves: parse_directions(input, moves)?,
+    })
+}
+
+fn part1(warehouse: &Warehouse) -> i32 {
+    let mut map = warehouse.map.clone();
+    let mut robot = warehouse.robot;
+
+    for m in &warehouse.moves {
+        let next_coord = robot + *m;
+        match map[next_coord] {
+            Some(Entity::Wall) => continue,
+            None => {
+                map_move(&mut map, &robot, m);
+                robot = next_coord
+            }
+            Some(Entity::Robot) => panic!("how even"),
+            Some(Entity::Box) => {
+                if move_box(&mut map, &next_coord, m) {
+                    map_move(&mut map, &robot, m);
+                    robot = next_coord;
+                }
+            }
+        }
+    }
+    map.find_all(&Some(Entity::Box))
+        .map(|coord| 100 * coord.0 as i32 + coord.1 as i32)
+        .sum()
+}
+
+fn try_move_large(map: &Map2, coords: &Point, dir: &Direction) -> Option<HashSet<Point>> {
+    match map[*coords] {
+        None => Some(HashSet::new()),
+        Some(Entity2::Wall) => None,
+        Some(Entity2::Robot) => match try_move_large(map, &(*coords + *dir), dir) {
+            Some(mut ts) => {
+                ts.insert(*coords);
+                Some(ts)
+            }
+            None => None,
+        },
+        Some(large_box) => {
+            let (box_left, box_right) = match large_box {
+                Entity2::BoxLeft => (*coords, *coords + Direction::Right),
+                Entity2::BoxRight => (*coords + Direction::Left, *coords),
+                _ => panic!("can't happen"),
+            };
+            match dir {
+                Direction::Left => {
+                    let next = box_left + *dir;
+                    match try_move_large(map, &next, dir) {
+                        None => None,
+                        Some(mut ts) => {
+                            ts.insert(box_left);
+                            ts.insert(box_right);
+                            Some(ts)
+                        }
+                    }
+                }
+                Direction::Right => {
+                    let next = box_right + *dir;
+                    match try_move_large(map, &next, dir) {
+                        None => None,
+                        Some(mut ts) => {
+                            ts.insert(box_right);
+                            ts.insert(box_left);
+                            Some(ts)
+                        }
+                    }
+                }
+                Direction::Up | Direction::Down => {
+                    let next_left = box_left + *dir;
+                    let next_right = box_right + *dir;
+
+                    let mut ts_left = try_move_large(map, &next_left, dir)?;
+                    if map[next_left] == Some(Entity2::BoxLeft) {
+                        // another box perfectly aligned above/below, no need to check right
+                        ts_left.insert(box_left);
+                        ts_left.insert(box_right);
+                        return Some(ts_left);
+                    }
+                    match try_move_large(map, &next_right, dir) {
+                        None => None,
+                        Some(ts) => {
+                            let mut ts: HashSet<Point> = ts.union(&ts_left).copied().collect();
+                            ts.insert(box_left);
+                            ts.insert(box_right);
+                            Some(ts)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The doubled-width warehouse after the robot has made all its moves.
+fn run_wide(warehouse: &Warehouse) -> Map2 {
+    let mut map: Map2 = Grid::filled(warehouse.map.height, warehouse.map.width * 2, None);
+    for ((row, col), entity) in warehouse.map.iter() {
+        let left_coord = (row, col * 2);
+        let right_coord = (row, col * 2 + 1);
+        let entity = match entity {
+            Some(Entity::Wall) => (Entity2::Wall, Some(Entity2::Wall)),
+            Some(Entity::Box) => (Entity2::BoxLeft, Some(Entity2::BoxRight)),
+            Some(Entity::Robot) => (Entity2::Robot, None),
+            None => continue,
+        };
+        map[left_coord] = Some(entity.0);
+        map[right_coord] = entity.1;
+    }
+    let mut robot = Point::new(warehouse.robot.x * 2, warehouse.robot.y);
+    for d in &warehouse.moves {
+        let move_coords = try_move_large(&map, &robot, d);
+        if let Some(ts) = move_coords {
+            apply_map_moves(&mut map, &ts, d);
+            robot += *d;
+        }
+    }
+    map
+}
+
+fn part2(warehouse: &Warehouse) -> i32 {
+    run_wide(warehouse)
+        .find_all(&Some(Entity2::BoxLeft))
+        .map(|coord| 100 * coord.0 as i32 + coord.1 as i32)
+        .sum()
+}
+
+/// The doubled-width warehouse of part 2 after the robot has made all its moves.
+pub fn draw_wide(warehouse: &Warehouse) -> Canvas {
+    draw_map2(&run_wide(warehouse))
+}
+
+fn draw_map2(map2: &Map2) -> Canvas {
+    Canvas::from_grid(map2, |entity| match entity {
+        None => Cell::EMPTY,
+        Some(Entity2::BoxLeft) => Cell::new('[', Color::ORANGE),
+        Some(Entity2::BoxRight) => Cell::new(']', Color::ORANGE),
+        Some(Entity2::Wall) => Cell::WALL,
+        Some(Entity2::Robot) => Cell::ROBOT,
+    })
+}
+
+/// Part 1 sums the box coordinates after the robot's moves, part 2 in the doubled-width warehouse.
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_warehouse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
@@ -0,0 +1,48 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day15::{draw_wide, Day15};
+
+const SMALL_EXAMPLE: &str = include_str!("../../example.txt");
+const EXAMPLE: &str = include_str!("../../inputs_test.txt");
+
+#[test]
+fn small_example() {
+    assert_eq!(Day15::part1(&parse::<Day15>(SMALL_EXAMPLE)), 2028);
+}
+
+#[test]
+fn example() {
+    let input = parse::<Day15>(EXAMPLE);
+    assert_eq!(Day15::part1(&input), 10092);
+    assert_eq!(Day15::part2(&input), 9021);
+}
+
+#[test]
+fn draws_wide_warehouse() {
+    let mut plain = Vec::new();
+    draw_wide(&parse::<Day15>(EXAMPLE))
+        .write_to(&mut plain, false)
+        .unwrap();
+    let expected = "\
+####################
+##[].......[].[][]##
+##[]...........[].##
+##[]........[][][]##
+##[]......[]....[]##
+##..##......[]....##
+##..[]............##
+##..@......[].[][]##
+##......[][]..[]..##
+####################
+";
+    assert_eq!(String::from_utf8(plain).unwrap(), expected);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day15>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
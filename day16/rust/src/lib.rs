@@ -0,0 +1,196 @@
+//! Day 16: Reindeer Maze.
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Point};
+use render::{Canvas, Cell};
+use search::{astar, bfs, Paths};
+use std::collections::HashSet;
+
+/// The reindeer maze, which starts facing east.
+// The maze is surrounded by walls, so moving never leaves the grid.
+#[derive(Debug, Clone)]
+pub struct Maze {
+    pub walls: Grid<bool>,
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Maze {
+    /// Parses the map with walls `#`, the start `S` and the end `E`, which has to be reachable
+    /// from the start.
+    pub fn parse(maze_str: &str) -> Result<Self, ParseError> {
+        let maze = Grid::parse(maze_str)?;
+        let maze = Maze {
+            walls: maze.map(|&c| c == '#'),
+            start: maze
+                .find(&'S')
+                .map(Point::from)
+                .ok_or_else(|| ParseError::missing(maze_str, "start 'S'"))?,
+            end: maze
+                .find(&'E')
+                .map(Point::from)
+                .ok_or_else(|| ParseError::missing(maze_str, "end 'E'"))?,
+        };
+        let reachable = bfs(
+            maze.start,
+            |&pos| {
+                pos.neighbors4()
+                    .filter(|&next| maze.walls.at(next) == Some(&false))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| pos == maze.end,
+        );
+        if reachable.goal().is_none() {
+            return Err(ParseError::missing(maze_str, "path from 'S' to 'E'"));
+        }
+        Ok(maze)
+    }
+
+    /// Lowest score of getting from the start to the end, if the end can be reached.
+    pub fn lowest_score(&self) -> Option<i32> {
+        part1(&self.walls, self.start, self.end)
+    }
+
+    /// Tiles on any of the paths with the lowest score.
+    pub fn best_seats(&self) -> HashSet<Point> {
+        best_seats(&self.walls, self.start, self.end).unwrap_or_default()
+    }
+
+    /// The maze with the tiles of `seats` marked.
+    pub fn draw(&self, seats: &HashSet<Point>) -> Canvas {
+        let mut canvas =
+            Canvas::from_grid(
+                &self.walls,
+                |&wall| {
+                    if wall {
+                        Cell::WALL
+                    } else {
+                        Cell::EMPTY
+                    }
+                },
+            );
+        canvas.overlay(seats.iter().copied(), Cell::PATH);
+        canvas
+    }
+}
+
+#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+struct Node {
+    coords: Point,
+    direction: Direction,
+}
+impl Node {
+    fn follow(&self) -> Node {
+        Node {
+            coords: self.coords + self.direction,
+            direction: self.direction,
+        }
+    }
+    fn turn_clockwise(&self) -> Node {
+        Node {
+            coords: self.coords,
+            direction: self.direction.turn_right(),
+        }
+    }
+    fn turn_counter_clockwise(&self) -> Node {
+        Node {
+            coords: self.coords,
+            direction: self.direction.turn_left(),
+        }
+    }
+}
+
+/// Moving on costs 1, turning on the spot 1000.
+fn neighbors(walls: &Grid<bool>, node: &Node) -> Vec<(Node, i32)> {
+    let mut neighbors = vec![
+        (node.turn_clockwise(), 1000),
+        (node.turn_counter_clockwise(), 1000),
+    ];
+    let follow = node.follow();
+    if !walls[follow.coords] {
+        neighbors.push((follow, 1));
+    }
+    neighbors
+}
+
+/// Cheapest ways from the start to the end tile, facing any direction there.
+fn search(walls: &Grid<bool>, start_xy: Point, end_xy: Point) -> Paths<Node, i32> {
+    let start_node = Node {
+        coords: start_xy,
+        direction: Direction::Right,
+    };
+    // Turning keeps the distance and moving changes it by one, so this never overestimates.
+    astar(
+        start_node,
+        |node| neighbors(walls, node),
+        |node| node.coords.manhattan(end_xy) as i32,
+        |node| node.coords == end_xy,
+    )
+}
+
+fn part1(walls: &Grid<bool>, start_xy: Point, end_xy: Point) -> Option<i32> {
+    let paths = search(walls, start_xy, end_xy);
+    paths.distance(paths.goal()?)
+}
+
+fn best_seats(walls: &Grid<bool>, start_xy: Point, end_xy: Point) -> Option<HashSet<Point>> {
+    let paths = search(walls, start_xy, end_xy);
+    let best = paths.distance(paths.goal()?)?;
+    let ends = Direction::ALL
+        .into_iter()
+        .map(|direction| Node {
+            coords: end_xy,
+            direction,
+        })
+        .filter(|end| paths.distance(end) == Some(best));
+    Some(
+        paths
+            .nodes_on_paths_to(ends)
+            .into_iter()
+            .map(|node| node.coords)
+            .collect(),
+    )
+}
+
+fn part2(maze: &Maze) -> Option<i32> {
+    let visited_set = best_seats(&maze.walls, maze.start, maze.end)?;
+    Some(visited_set.len() as i32)
+}
+
+/// Part 1 finds the lowest score through the maze, part 2 counts the tiles on any best path.
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Maze::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input
+            .lowest_score()
+            .expect("parsing checks that the end is reachable")
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input).expect("parsing checks that the end is reachable")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_unreachable_ends() {
+        let error = Maze::parse("#####\n#S#E#\n#####").unwrap_err();
+        assert_eq!(error.message, "missing path from 'S' to 'E'");
+        assert_eq!(
+            Maze::parse("#####\n#S.E#\n#####").unwrap().lowest_score(),
+            Some(2)
+        );
+    }
+}
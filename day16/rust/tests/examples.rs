@@ -0,0 +1,29 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day16::Day16;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+const EXAMPLE2: &str = include_str!("../../example2.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day16>(EXAMPLE);
+    assert_eq!(Day16::part1(&input), 7036);
+    assert_eq!(Day16::part2(&input), 45);
+}
+
+#[test]
+fn example2() {
+    let input = parse::<Day16>(EXAMPLE2);
+    assert_eq!(Day16::part1(&input), 11048);
+    assert_eq!(Day16::part2(&input), 64);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day16>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../maze.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,271 @@
+//! Day 17: Chronospatial Computer.
+
+use common::{diagnostic, parse_token, ParseError, Solution};
+use regex::Regex;
+
+/// Why the instruction `opcode` cannot run with `operand`, if it cannot.
+fn invalid_operand(opcode: u64, operand: Option<u64>) -> Option<&'static str> {
+    match (opcode, operand) {
+        (_, None) => Some("expected an operand after the last opcode"),
+        (0 | 2 | 5 | 6 | 7, Some(7)) => Some("combo operand 7 is reserved"),
+        // a jump to an odd position would read operands as opcodes, which is not checked
+        (3, Some(target)) if target % 2 == 1 => Some("expected a jump to an even position"),
+        _ => None,
+    }
+}
+
+/// The 3-bit computer: three registers, a program of 3-bit numbers and an instruction pointer.
+#[derive(Clone, Debug)]
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    program: Vec<u64>,
+    i_ptr: usize,
+}
+impl Computer {
+    /// Computer about to run `program`, which has to consist of 3-bit numbers, give each opcode
+    /// an operand, never use the reserved combo operand 7 and only jump to even positions.
+    pub fn new(a: u64, b: u64, c: u64, program: Vec<u64>) -> Self {
+        assert!(program.iter().all(|&x| x < 8), "program is not 3-bit");
+        for instruction in program.chunks(2) {
+            if let Some(problem) = invalid_operand(instruction[0], instruction.get(1).copied()) {
+                panic!("invalid program: {}", problem);
+            }
+        }
+        Computer {
+            a,
+            b,
+            c,
+            program,
+            i_ptr: 0,
+        }
+    }
+
+    /// Parses the register values and the program from the puzzle input.
+    pub fn from_string(input_str: &str) -> Result<Self, ParseError> {
+        let register = |name: &str| {
+            Regex::new(&format!(r"Register {}: (\S+)", name))
+                .unwrap()
+                .captures(input_str)
+                .ok_or_else(|| ParseError::missing(input_str, &format!("register {}", name)))
+                .and_then(|c| parse_token(input_str, c.get(1).unwrap().as_str()))
+        };
+        let tokens: Vec<&str> = Regex::new(r"(?m)Program: (.*)$")
+            .unwrap()
+            .captures(input_str)
+            .ok_or_else(|| ParseError::missing(input_str, "program"))?
+            .get(1)
+            .unwrap()
+            .as_str()
+            .split(",")
+            .collect();
+        let program: Vec<u64> = tokens
+            .iter()
+            .map(|x| match parse_token(input_str, x)? {
+                value @ 0..=7 => Ok(value),
+                _ => Err(ParseError::at(input_str, x, "expected a 3-bit number")),
+            })
+            .collect::<Result<_, _>>()?;
+        for (idx, instruction) in program.chunks(2).enumerate() {
+            if let Some(problem) = invalid_operand(instruction[0], instruction.get(1).copied()) {
+                // point at the operand, or at the opcode that lacks one
+                let token = tokens[(2 * idx + 1).min(tokens.len() - 1)];
+                return Err(ParseError::at(input_str, token, problem));
+            }
+        }
+        Ok(Computer::new(
+            register("A")?,
+            register("B")?,
+            register("C")?,
+            program,
+        ))
+    }
+
+    /// Current values of the registers A, B and C.
+    pub fn registers(&self) -> (u64, u64, u64) {
+        (self.a, self.b, self.c)
+    }
+
+    pub fn program(&self) -> &[u64] {
+        &self.program
+    }
+
+    pub fn set_a(&mut self, a: u64) {
+        self.a = a;
+    }
+
+    fn fetch_operand(&self) -> u64 {
+        self.program[self.i_ptr + 1]
+    }
+
+    fn fetch_combo_operand(&self) -> u64 {
+        let op = self.program[self.i_ptr + 1];
+        match op {
+            0..=3 => op,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            _ => unreachable!("combo operand {} is rejected by Computer::new", op),
+        }
+    }
+
+    fn current_instruction(&self) -> Option<u64> {
+        if !self.end_reached() {
+            Some(self.program[self.i_ptr])
+        } else {
+            None
+        }
+    }
+
+    /// Whether the instruction pointer has left the program, i.e. the computer halted.
+    pub fn end_reached(&self) -> bool {
+        self.i_ptr >= self.program.len()
+    }
+
+    fn next_instruction(&mut self) {
+        self.i_ptr += 2;
+    }
+
+    fn jump_instruction(&mut self, pos: usize) {
+        self.i_ptr = pos;
+    }
+
+    /// Moves back to the first instruction and clears all registers.
+    pub fn reset(&mut self) {
+        self.i_ptr = 0;
+        self.a = 0;
+        self.b = 0;
+        self.c = 0;
+    }
+
+    /// Runs until the next `out` instruction and returns its value, or `None` once halted.
+    pub fn compute_next_output(&mut self) -> Option<u64> {
+        loop {
+            let opcode = match self.current_instruction() {
+                Some(opcode) => opcode,
+                None => {
+                    return None;
+                }
+            };
+
+            match opcode {
+                0 => {
+                    let denom = self.fetch_combo_operand();
+                    self.a /= 2u64.pow(denom as u32);
+                    self.next_instruction();
+                }
+                1 => {
+                    self.b ^= self.fetch_operand();
+                    self.next_instruction();
+                }
+                2 => {
+                    self.b = self.fetch_combo_operand() % 8;
+                    self.next_instruction();
+                }
+                3 => {
+                    if self.a == 0 {
+                        self.next_instruction();
+                        continue;
+                    }
+                    self.jump_instruction(self.fetch_operand() as usize);
+                }
+                4 => {
+                    self.b ^= self.c;
+                    self.next_instruction();
+                }
+                5 => {
+                    let output = self.fetch_combo_operand() % 8;
+                    self.next_instruction();
+                    return Some(output);
+                }
+                6 => {
+                    let denom = self.fetch_combo_operand();
+                    self.b = self.a / 2u64.pow(denom as u32);
+                    self.next_instruction();
+                }
+                7 => {
+                    let denom = self.fetch_combo_operand();
+                    self.c = self.a / 2u64.pow(denom as u32);
+                    self.next_instruction();
+                }
+                _ => panic!("Invalid instruction {}", opcode),
+            }
+        }
+    }
+
+    /// Runs until the computer halts and returns everything it output.
+    pub fn compute(&mut self) -> Vec<u64> {
+        let mut outputs: Vec<u64> = Vec::new();
+        while let Some(output) = self.compute_next_output() {
+            outputs.push(output);
+        }
+        outputs
+    }
+}
+
+fn part1(comp: &Computer) -> String {
+    let mut comp = comp.clone();
+    comp.compute()
+        .iter()
+        .map(|x| x.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn part2(comp: &Computer) -> u64 {
+    let mut comp = comp.clone();
+
+    let mut search_candidates: Vec<u64> = Vec::new();
+    for i in 0..=0b1111111111 {
+        // all 'last' 10 bits predicting the first item correctly
+        comp.reset();
+        comp.a = i;
+        if comp.compute_next_output().unwrap() == *comp.program.first().unwrap() {
+            search_candidates.push(i);
+        }
+    }
+
+    let program = comp.program.clone();
+    for (idx, &target) in program.iter().enumerate().skip(1) {
+        let mut new_search_candidates: Vec<u64> = Vec::new();
+        search_candidates.iter().for_each(|cand| {
+            for new_first3 in 0..=0b111 {
+                let partial_cand = ((new_first3 as u64) << 7) | cand >> (3 * idx);
+                comp.reset();
+                comp.a = partial_cand;
+                if comp.compute_next_output().unwrap() == target {
+                    new_search_candidates
+                        .push(partial_cand << (3 * idx) | cand & ((1 << (3 * idx)) - 1));
+                }
+            }
+        });
+        search_candidates = new_search_candidates;
+        if search_candidates.is_empty() {
+            diagnostic!("No candidates found for {}", target);
+            break;
+        }
+    }
+    *search_candidates.iter().min().unwrap()
+}
+
+/// Part 1 prints the program's output, part 2 finds the register A that makes it print itself.
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Computer;
+    type Answer1 = String;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Computer::from_string(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
@@ -0,0 +1,44 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day17::Day17;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    assert_eq!(
+        Day17::part1(&parse::<Day17>(EXAMPLE)),
+        "4,6,3,5,6,3,5,2,1,0"
+    );
+}
+
+#[test]
+fn rejects_reserved_operands() {
+    let registers = "Register A: 1\nRegister B: 0\nRegister C: 0\n\n";
+    let error = Day17::parse(&format!("{}Program: 0,7,5,4\n", registers)).unwrap_err();
+    assert_eq!(
+        (error.line, error.column, error.message.as_str()),
+        (5, 12, "combo operand 7 is reserved")
+    );
+    // the literal operands of bxl and jnz may be 7, but jumps have to land on opcodes
+    assert!(Day17::parse(&format!("{}Program: 1,7,5,4\n", registers)).is_ok());
+    let error = Day17::parse(&format!("{}Program: 1,7,3,7\n", registers)).unwrap_err();
+    assert_eq!(
+        (error.column, error.message.as_str()),
+        (16, "expected a jump to an even position")
+    );
+    let error = Day17::parse(&format!("{}Program: 5,4,0\n", registers)).unwrap_err();
+    assert_eq!(
+        (error.column, error.message.as_str()),
+        (14, "expected an operand after the last opcode")
+    );
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day17>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,122 @@
+//! Day 18: RAM Run.
+
+use common::{parse_token, ParseError, Solution};
+use grid::{Coords, Grid, Point};
+use search::bfs;
+
+fn parse_coords(contents: &str) -> Result<Vec<Point>, ParseError> {
+    contents
+        .lines()
+        .map(|s| {
+            let (x, y) = s
+                .split_once(",")
+                .ok_or_else(|| ParseError::at(contents, s, "expected 'X,Y'"))?;
+            Ok(Point::new(
+                parse_token(contents, x)?,
+                parse_token(contents, y)?,
+            ))
+        })
+        .collect()
+}
+
+fn part1(obstacles: &[Point], max_x: i32, max_y: i32, first_bytes: usize) -> i32 {
+    let mut corrupted = Grid::filled(max_y as usize + 1, max_x as usize + 1, false);
+    for &byte in &obstacles[..first_bytes.min(obstacles.len())] {
+        if corrupted.contains(byte) {
+            corrupted[byte] = true;
+        }
+    }
+    let exit = (max_y as usize, max_x as usize);
+    let paths = bfs(
+        (0, 0),
+        |&node| {
+            corrupted
+                .neighbors4(node)
+                .filter(|&neighbor| !corrupted[neighbor])
+                .collect::<Vec<Coords>>()
+        },
+        |&node| node == exit,
+    );
+    paths.distance(&exit).map_or(0, |length| length as i32)
+}
+
+/// The first byte after which there is no way out, if the bytes ever cut it off.
+fn part2(obstacles: &[Point], max_x: i32, max_y: i32) -> Option<Point> {
+    let mut left = 0;
+    let mut right = obstacles.len();
+    while left < right {
+        let mid = (left + right) / 2;
+        let path_length = part1(obstacles, max_x, max_y, mid);
+        if path_length > 0 {
+            // still a path possible, got go further
+            left = mid + 1;
+        } else {
+            // path not possible, but can we find a better one
+            right = mid;
+        }
+    }
+    // the search also ends here when the way stays open after every byte
+    if left == obstacles.len() && part1(obstacles, max_x, max_y, left) > 0 {
+        return None;
+    }
+    left.checked_sub(1).map(|idx| obstacles[idx])
+}
+
+/// Falling bytes in a memory space, whose size differs between the example and the real input.
+pub struct Memory {
+    pub bytes: Vec<Point>,
+    pub max_x: i32,
+    pub max_y: i32,
+    /// Number of bytes that have fallen in part 1.
+    pub first_bytes: usize,
+}
+
+/// Memory spaces as (max_x, max_y, first_bytes): the example's and then the real input's.
+const SPACES: [(i32, i32, usize); 2] = [(6, 6, 12), (70, 70, 1024)];
+
+impl Memory {
+    /// Bytes falling into the example's memory space if they all land in it, and otherwise
+    /// into the real one.
+    pub fn new(bytes: Vec<Point>) -> Self {
+        let fits = |&(max_x, max_y, _): &(i32, i32, usize)| {
+            bytes
+                .iter()
+                .all(|byte| byte.x <= max_x as isize && byte.y <= max_y as isize)
+        };
+        let (max_x, max_y, first_bytes) = SPACES
+            .into_iter()
+            .find(fits)
+            .unwrap_or(SPACES[SPACES.len() - 1]);
+        Memory {
+            bytes,
+            max_x,
+            max_y,
+            first_bytes,
+        }
+    }
+}
+
+/// Part 1 finds the shortest way out after the first bytes fall, part 2 the byte that cuts it
+/// off, or `none` if the way stays open.
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Memory;
+    type Answer1 = i32;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Memory::new(parse_coords(input)?))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(&input.bytes, input.max_x, input.max_y, input.first_bytes)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(&input.bytes, input.max_x, input.max_y).map_or_else(
+            || "none".to_string(),
+            |Point { x, y }| format!("{},{}", x, y),
+        )
+    }
+}
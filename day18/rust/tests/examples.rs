@@ -0,0 +1,33 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day18::{Day18, Memory};
+
+const EXAMPLE: &str = include_str!("../../example_coords.txt");
+
+#[test]
+fn example() {
+    // The example memory space is 7x7 and only the first 12 bytes have fallen for part 1.
+    let input = parse::<Day18>(EXAMPLE);
+    assert_eq!((input.max_x, input.max_y, input.first_bytes), (6, 6, 12));
+    assert_eq!(Day18::part1(&input), 22);
+    assert_eq!(Day18::part2(&input), "6,1");
+}
+
+#[test]
+fn keeps_the_way_open() {
+    // fewer bytes than fall in part 1, none of them in the way
+    let input = parse::<Day18>("1,0\n1,1\n");
+    assert_eq!(Day18::part1(&input), 12);
+    assert_eq!(Day18::part2(&input), "none");
+    let input = Memory::new(Vec::new());
+    assert_eq!(Day18::part2(&input), "none");
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day18>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../coords.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,99 @@
+//! Day 19: Linen Layout.
+
+use common::input::two_sections;
+use common::{ParseError, Solution};
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+fn parse_inputs(input_str: &str) -> Result<(Vec<String>, Vec<String>), ParseError> {
+    let (patterns, designs) = two_sections(input_str, "designs after the patterns")?;
+    let patterns: Vec<_> = patterns.split(", ").map(String::from).collect();
+    let designs: Vec<_> = designs.lines().map(String::from).collect();
+    Ok((patterns, designs))
+}
+
+/// Number of designs that can be made from the towel patterns.
+pub fn possible_designs(patterns: &[String], designs: &[String]) -> usize {
+    let regex_str = format!("^({})*$", patterns.join("|"));
+    let regex = Regex::new(&regex_str).unwrap();
+    designs.iter().filter(|d| regex.is_match(d)).count()
+}
+
+struct PatternsUntilIndex {
+    patterns: Vec<String>,
+    design: String,
+    cache: RefCell<HashMap<usize, usize>>,
+}
+
+impl PatternsUntilIndex {
+    fn new(patterns: &[String], design: &str) -> Self {
+        let mut cache: HashMap<usize, usize> = HashMap::new();
+        cache.insert(
+            0,
+            patterns
+                .iter()
+                .any(|p| p.len() == 1 && design.starts_with(p)) as usize,
+        );
+        Self {
+            patterns: patterns.to_vec(),
+            design: design.to_string(),
+            cache: RefCell::new(cache),
+        }
+    }
+
+    fn compute_index(&self, index: usize) -> usize {
+        if self.cache.borrow().contains_key(&index) {
+            return self.cache.borrow()[&index];
+        }
+        let mut count = 0;
+        for pattern in &self.patterns {
+            if index < pattern.len() - 1 {
+                continue;
+            }
+            if &self.design[index - (pattern.len() - 1)..=index] == pattern {
+                count += if index >= pattern.len() {
+                    self.compute_index(index - pattern.len())
+                } else {
+                    1
+                };
+            }
+        }
+        self.cache.borrow_mut().insert(index, count);
+        count
+    }
+
+    fn compute(&mut self) -> usize {
+        self.compute_index(self.design.len() - 1)
+    }
+}
+
+/// Number of different ways to make `design` from the towel patterns, each usable any number
+/// of times.
+pub fn arrangements(patterns: &[String], design: &str) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+    PatternsUntilIndex::new(patterns, design).compute()
+}
+
+/// The input is the towel patterns and the designs to make from them.
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<String>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_inputs(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        possible_designs(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.1.iter().map(|d| arrangements(&input.0, d)).sum()
+    }
+}
@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day19::Day19;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day19>(EXAMPLE);
+    assert_eq!(Day19::part1(&input), 6);
+    assert_eq!(Day19::part2(&input), 16);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day19>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
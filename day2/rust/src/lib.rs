@@ -0,0 +1,348 @@
+//! Day 2: Red-Nosed Reports.
+
+use common::{parse_token, ParseError, Solution};
+use std::fmt::{Display, Formatter};
+use std::ops::Index;
+
+/// How much adjacent levels of a safe report may differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StepBounds {
+    pub min: u32,
+    pub max: u32,
+}
+
+impl StepBounds {
+    pub fn new(min: u32, max: u32) -> Self {
+        assert!(min <= max, "empty step bounds {}..={}", min, max);
+        StepBounds { min, max }
+    }
+
+    /// Whether going from level `a` to `b` is a step in the given direction within the bounds.
+    fn allows(&self, a: u32, b: u32, increasing: bool) -> bool {
+        let in_direction = if increasing { a <= b } else { a >= b };
+        in_direction && (self.min..=self.max).contains(&a.abs_diff(b))
+    }
+}
+
+impl Default for StepBounds {
+    /// Steps of 1 to 3, as in the puzzle.
+    fn default() -> Self {
+        StepBounds { min: 1, max: 3 }
+    }
+}
+
+/// Rule broken by a step between adjacent levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    /// The levels turn from increasing to decreasing or back.
+    Direction,
+    /// The levels differ by less than the minimum step, e.g. stay the same.
+    StepTooSmall,
+    /// The levels differ by more than the maximum step.
+    StepTooLarge,
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Rule::Direction => "changes direction",
+            Rule::StepTooSmall => "step too small",
+            Rule::StepTooLarge => "step too large",
+        })
+    }
+}
+
+/// First step of a report that breaks a rule, between the levels at `left` and `left + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub left: usize,
+    pub rule: Rule,
+}
+
+/// Why a report is unsafe, and how to fix it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    /// `None` if the report is safe.
+    pub violation: Option<Violation>,
+    /// Indices of the levels whose removal alone makes an unsafe report safe.
+    pub fixes: Vec<usize>,
+}
+
+impl Diagnosis {
+    pub fn is_safe(&self) -> bool {
+        self.violation.is_none()
+    }
+}
+
+/// First step of `levels` breaking a rule. The direction is that of the first step that
+/// changes the level.
+fn first_violation(levels: &[u32], bounds: StepBounds) -> Option<Violation> {
+    let increasing = levels
+        .windows(2)
+        .find(|pair| pair[0] != pair[1])
+        .is_none_or(|pair| pair[0] < pair[1]);
+    levels.windows(2).enumerate().find_map(|(left, pair)| {
+        let step = pair[0].abs_diff(pair[1]);
+        let rule = if step != 0 && (pair[0] < pair[1]) != increasing {
+            Rule::Direction
+        } else if step < bounds.min {
+            Rule::StepTooSmall
+        } else if step > bounds.max {
+            Rule::StepTooLarge
+        } else {
+            return None;
+        };
+        Some(Violation { left, rule })
+    })
+}
+
+/// Levels of one reactor report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    levels: Vec<u32>,
+}
+impl Report {
+    /// Report with the given levels, of which there has to be at least one. A single level is
+    /// trivially safe.
+    pub fn new(levels: Vec<u32>) -> Self {
+        assert!(!levels.is_empty(), "a report needs at least one level");
+        Report { levels }
+    }
+
+    pub fn levels(&self) -> &[u32] {
+        &self.levels
+    }
+
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
+    }
+
+    /// Whether the levels all increase or all decrease, by 1 to 3 each step.
+    pub fn is_safe(&self) -> bool {
+        self.is_safe_with_removals(0)
+    }
+
+    /// Whether the report is safe after removing at most one level.
+    pub fn is_safe_with_dampener(&self) -> bool {
+        self.is_safe_with_removals(1)
+    }
+
+    /// Whether the report is safe after removing at most `k` levels.
+    pub fn is_safe_with_removals(&self, k: usize) -> bool {
+        self.is_safe_within(StepBounds::default(), k)
+    }
+
+    /// Whether the levels all increase or all decrease by steps within `bounds`, after removing
+    /// at most `removals` levels. Takes O(n * removals) time.
+    pub fn is_safe_within(&self, bounds: StepBounds, removals: usize) -> bool {
+        [true, false].into_iter().any(|increasing| {
+            self.longest_run(bounds, increasing, removals) + removals >= self.len()
+        })
+    }
+
+    /// The first step breaking a rule of `bounds`, and which single levels could be removed to
+    /// make the report safe.
+    pub fn diagnose(&self, bounds: StepBounds) -> Diagnosis {
+        let violation = first_violation(&self.levels, bounds);
+        let fixes = match violation {
+            None => Vec::new(),
+            Some(_) => (0..self.len())
+                .filter(|&removed| {
+                    let mut levels = self.levels.clone();
+                    levels.remove(removed);
+                    first_violation(&levels, bounds).is_none()
+                })
+                .collect(),
+        };
+        Diagnosis { violation, fixes }
+    }
+
+    /// Most levels that can be kept so that each step between kept levels is within `bounds` in
+    /// the given direction, considering only runs that skip at most `max_skip` levels at once.
+    fn longest_run(&self, bounds: StepBounds, increasing: bool, max_skip: usize) -> usize {
+        // longest[i]: longest run ending at level i
+        let mut longest: Vec<usize> = Vec::with_capacity(self.len());
+        for (idx, &level) in self.levels.iter().enumerate() {
+            let previous = idx.saturating_sub(max_skip + 1)..idx;
+            let run = previous
+                .filter(|&prev| bounds.allows(self.levels[prev], level, increasing))
+                .map(|prev| longest[prev] + 1)
+                .max()
+                .unwrap_or(1);
+            longest.push(run);
+        }
+        longest.into_iter().max().unwrap_or(0)
+    }
+}
+impl Index<usize> for Report {
+    type Output = u32;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.levels[index]
+    }
+}
+
+fn part1(reports: &[Report]) -> u32 {
+    reports.iter().filter(|report| report.is_safe()).count() as u32
+}
+
+fn part2(reports: &[Report]) -> u32 {
+    reports
+        .iter()
+        .filter(|report| report.is_safe_with_dampener())
+        .count() as u32
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Report>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let levels = line
+                .split_whitespace()
+                .map(|level| parse_token(input, level))
+                .collect::<Result<Vec<u32>, _>>()?;
+            if levels.is_empty() {
+                return Err(ParseError::at(input, line, "expected at least one level"));
+            }
+            Ok(Report::new(levels))
+        })
+        .collect()
+}
+
+/// Part 1 counts the safe reports, part 2 also those made safe by removing one level.
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Report>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Safe after removing at most one level, trying every removal.
+    fn brute_force_dampener(report: &Report) -> bool {
+        report.is_safe()
+            || (0..report.len()).any(|removed| {
+                let mut levels = report.levels().to_vec();
+                levels.remove(removed);
+                levels.is_empty() || Report::new(levels).is_safe()
+            })
+    }
+
+    /// Reports that mostly step by small amounts in one direction, so that many of them are
+    /// safe or one removal away from it.
+    fn reports() -> impl Strategy<Value = Report> {
+        prop_oneof![
+            prop::collection::vec(0u32..12, 1..9),
+            (20u32..40, prop::collection::vec(-4i32..=4, 1..10)).prop_map(|(start, steps)| {
+                let mut levels = vec![start];
+                for step in steps {
+                    levels.push(levels.last().unwrap().saturating_add_signed(step));
+                }
+                levels
+            }),
+        ]
+        .prop_map(Report::new)
+    }
+
+    /// Safe within `bounds` after removing at most `k` levels, trying every set of removals.
+    fn brute_force_removals(report: &Report, bounds: StepBounds, k: usize) -> bool {
+        let levels = report.levels();
+        (0u32..1 << levels.len())
+            .filter(|removed| removed.count_ones() as usize <= k)
+            .any(|removed| {
+                let kept: Vec<u32> = (0..levels.len())
+                    .filter(|idx| removed & (1 << idx) == 0)
+                    .map(|idx| levels[idx])
+                    .collect();
+                [true, false].into_iter().any(|increasing| {
+                    kept.windows(2)
+                        .all(|pair| bounds.allows(pair[0], pair[1], increasing))
+                })
+            })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(4000))]
+
+        #[test]
+        fn dampener_matches_brute_force(report in reports()) {
+            prop_assert_eq!(report.is_safe_with_dampener(), brute_force_dampener(&report));
+        }
+
+        #[test]
+        fn diagnosis_matches_safety(report in reports()) {
+            let diagnosis = report.diagnose(StepBounds::default());
+            prop_assert_eq!(diagnosis.is_safe(), report.is_safe());
+            prop_assert_eq!(
+                diagnosis.is_safe() || !diagnosis.fixes.is_empty(),
+                report.is_safe_with_dampener()
+            );
+        }
+
+        #[test]
+        fn removals_match_brute_force(
+            report in reports(),
+            min in 0u32..3,
+            extra in 0u32..4,
+            k in 0usize..4,
+        ) {
+            let bounds = StepBounds::new(min, min + extra);
+            prop_assert_eq!(
+                report.is_safe_within(bounds, k),
+                brute_force_removals(&report, bounds, k)
+            );
+        }
+    }
+
+    #[test]
+    fn diagnoses_example() {
+        let diagnose = |levels: Vec<u32>| Report::new(levels).diagnose(StepBounds::default());
+        let diagnosis = diagnose(vec![1, 2, 7, 8, 9]);
+        let violation = Violation {
+            left: 1,
+            rule: Rule::StepTooLarge,
+        };
+        assert_eq!(
+            (diagnosis.violation, diagnosis.fixes),
+            (Some(violation), vec![])
+        );
+        let diagnosis = diagnose(vec![1, 3, 2, 4, 5]);
+        assert_eq!(diagnosis.violation.unwrap().rule, Rule::Direction);
+        assert_eq!(diagnosis.fixes, [1, 2]);
+        let diagnosis = diagnose(vec![8, 6, 4, 4, 1]);
+        assert_eq!(diagnosis.violation.unwrap().rule, Rule::StepTooSmall);
+        assert_eq!(diagnosis.fixes, [2, 3]);
+        assert!(diagnose(vec![1, 3, 6, 7, 9]).is_safe());
+    }
+
+    #[test]
+    fn accepts_single_levels() {
+        let reports = parse_reports("7\n1 2\n").unwrap();
+        assert_eq!(reports[0].levels(), [7]);
+        assert!(reports[0].is_safe());
+        assert!(reports[0].diagnose(StepBounds::default()).is_safe());
+        let error = parse_reports("1 2\n\n").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}
@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day2::Day2;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day2>(EXAMPLE);
+    assert_eq!(Day2::part1(&input), 2);
+    assert_eq!(Day2::part2(&input), 4);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day2>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../reports.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
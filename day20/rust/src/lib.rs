@@ -0,0 +1,151 @@
+//! Day 20: Race Condition.
+
+use common::{ParseError, Solution};
+use grid::{Direction, Grid, Point};
+use search::bfs;
+use std::collections::HashMap;
+
+/// A racetrack map with walls `#`, track `.`, the start `S` and the end `E`.
+#[derive(Debug)]
+pub struct Maze {
+    maze: Grid<char>,
+    start: Point,
+    path: Vec<Point>,
+}
+
+impl Maze {
+    /// Parses the map, which needs a start and an end connected by the track.
+    pub fn new(input_str: &str) -> Result<Self, ParseError> {
+        let maze = Grid::parse(input_str)?;
+        let start = maze
+            .find(&'S')
+            .map(Point::from)
+            .ok_or_else(|| ParseError::missing(input_str, "start 'S'"))?;
+        if maze.find(&'E').is_none() {
+            return Err(ParseError::missing(input_str, "end 'E'"));
+        }
+        let paths = bfs(
+            start,
+            |&pos| {
+                pos.neighbors4()
+                    .filter(|&next| matches!(maze.at(next), Some('.' | 'E')))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| maze.at(pos) == Some(&'E'),
+        );
+        let path = paths
+            .goal()
+            .and_then(|end| paths.path_to(end))
+            .ok_or_else(|| ParseError::missing(input_str, "track from 'S' to 'E'"))?;
+        Ok(Self { maze, start, path })
+    }
+
+    /// The racetrack from start to end; there is only one.
+    pub fn path(&self) -> &[Point] {
+        &self.path
+    }
+
+    /// The start tile `S`.
+    pub fn start(&self) -> Point {
+        self.start
+    }
+
+    /// The tile at `pos`, if it lies on the map.
+    pub fn at(&self, pos: Point) -> Option<&char> {
+        self.maze.at(pos)
+    }
+}
+
+fn part1(maze: &Maze, min_saving: i32) -> usize {
+    let path = maze.path();
+    let distance_to_end: HashMap<Point, i32> = path
+        .iter()
+        .enumerate()
+        .map(|(i, pos)| (*pos, (path.len() - 1 - i) as i32))
+        .collect();
+    let mut viable_cheats = 0;
+    for &node in path {
+        for d in Direction::ALL {
+            let next = node + d;
+            if let Some('#') = maze.at(next) {
+                let next_next = next + d;
+                if let Some('.' | 'E') = maze.at(next_next) {
+                    let distance_gain = distance_to_end[&node] - distance_to_end[&next_next] - 2;
+                    viable_cheats += if distance_gain >= min_saving { 1 } else { 0 };
+                }
+            }
+        }
+    }
+    viable_cheats
+}
+
+fn part2(maze: &Maze, min_saving: i32) -> usize {
+    let path = maze.path();
+    let distance_to_end: HashMap<Point, i32> = path
+        .iter()
+        .enumerate()
+        .map(|(i, pos)| (*pos, (path.len() - 1 - i) as i32))
+        .collect();
+    let mut viable_cheats = 0;
+    let min_saving_idx = min_saving as usize;
+    for (idx, &node) in path
+        .iter()
+        .take(path.len().saturating_sub(min_saving_idx))
+        .enumerate()
+    {
+        viable_cheats += path
+            .iter()
+            .skip(idx + min_saving_idx)
+            .map(|n| {
+                let dist = node.manhattan(*n) as i32;
+                if dist > 20 {
+                    return 0;
+                }
+                (distance_to_end[&node] - distance_to_end[n] - dist >= min_saving) as usize
+            })
+            .sum::<usize>();
+    }
+    viable_cheats
+}
+
+/// A racetrack and the number of picoseconds a cheat has to save to be counted.
+pub struct Racetrack {
+    pub maze: Maze,
+    pub min_saving: i32,
+}
+
+/// Both parts count the cheats saving at least `min_saving` picoseconds, lasting up to 2 and 20.
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Racetrack;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Racetrack {
+            maze: Maze::new(input)?,
+            min_saving: 100,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(&input.maze, input.min_saving)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(&input.maze, input.min_saving)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_disconnected_tracks() {
+        let error = Maze::new("#####\n#S#E#\n#####").unwrap_err();
+        assert_eq!(error.message, "missing track from 'S' to 'E'");
+        assert_eq!(Maze::new("#####\n#S.E#\n#####").unwrap().path().len(), 3);
+    }
+}
@@ -0,0 +1,24 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day20::Day20;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    // The example track is too short for cheats saving 100 picoseconds.
+    let mut input = parse::<Day20>(EXAMPLE);
+    input.min_saving = 20;
+    assert_eq!(Day20::part1(&input), 5);
+    input.min_saving = 72;
+    assert_eq!(Day20::part2(&input), 29);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day20>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../input.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
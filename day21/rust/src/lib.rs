@@ -0,0 +1,253 @@
+//! Day 21: Keypad Conundrum.
+
+use common::{char_at, parse_token, ParseError, Solution};
+use grid::{Direction, Point};
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+type PadNumDir = char;
+type PadNum = char;
+type Dir = char;
+
+fn generate_adj(pad: Vec<Vec<PadNumDir>>) -> HashMap<PadNumDir, Vec<(PadNumDir, Dir)>> {
+    let mut adj: HashMap<PadNumDir, Vec<(PadNumDir, Dir)>> = HashMap::new();
+    for &c in pad.iter().flat_map(|row| row.iter()) {
+        if c == '\0' {
+            continue;
+        }
+        adj.insert(c, vec![]);
+    }
+    for (y, x) in itertools::iproduct!(0..pad.len(), 0..pad[0].len()) {
+        let current = pad[y][x];
+        if current == '\0' {
+            continue;
+        }
+        for direction in Direction::ALL {
+            let neighbor = Point::from((y, x)) + direction;
+            if let Some((ny, nx)) = neighbor.coords() {
+                let Some(&neighbor) = pad.get(ny).and_then(|row| row.get(nx)) else {
+                    continue;
+                };
+                if neighbor == '\0' {
+                    continue;
+                }
+                adj.get_mut(&current)
+                    .unwrap()
+                    .push((neighbor, direction.arrow()));
+            }
+        }
+    }
+    adj
+}
+lazy_static! {
+    static ref NUMPAD: Vec<Vec<char>> = vec![
+        vec!['7', '8', '9'],
+        vec!['4', '5', '6'],
+        vec!['1', '2', '3'],
+        vec!['\0', '0', 'A']
+    ];
+    static ref NUM_CHARS: Vec<char> = vec!['1', '2', '3', '4', '5', '6', '7', '8', '9', '0', 'A'];
+    static ref NUMPAD_ADJ: HashMap<PadNumDir, Vec<(PadNumDir, Dir)>> = generate_adj(NUMPAD.clone());
+    static ref DIRPAD: Vec<Vec<char>> = vec![vec!['\0', '^', 'A'], vec!['<', 'v', '>'],];
+    static ref DIR_CHARS: Vec<char> = vec!['^', 'v', '<', '>', 'A'];
+    static ref DIRPAD_ADJ: HashMap<PadNumDir, Vec<(PadNumDir, Dir)>> = generate_adj(DIRPAD.clone());
+    static ref PAD_PATHS: HashMap<(PadNumDir, PadNumDir), Vec<Vec<Dir>>> = generate_all_pad_paths();
+    static ref SHORTEST_PATH_PAIRS: Mutex<HashMap<(PadNum, PadNum, i32), usize>> =
+        Mutex::new(HashMap::new());
+    static ref SHORTEST_PATHS: Mutex<HashMap<(String, i32), Vec<Dir>>> = Mutex::new(HashMap::new());
+}
+
+fn generate_all_pad_paths() -> HashMap<(PadNum, PadNum), Vec<Vec<Dir>>> {
+    // Floyd-Warshall algorithm, yes this is overkill...
+    let mut distances: HashMap<(PadNum, PadNum), i32> = HashMap::new();
+    let mut paths: HashMap<(PadNum, PadNum), Vec<Vec<Dir>>> = HashMap::new();
+
+    for numpad in [true, false] {
+        let chars = if numpad {
+            NUM_CHARS.clone()
+        } else {
+            DIR_CHARS.clone()
+        };
+        let adj = if numpad {
+            NUMPAD_ADJ.clone()
+        } else {
+            DIRPAD_ADJ.clone()
+        };
+
+        for (node_i, node_js) in adj.iter() {
+            distances.insert((*node_i, *node_i), 0);
+            paths.insert((*node_i, *node_i), vec![vec![]]);
+            for (node_j, dir) in node_js {
+                distances.insert((*node_i, *node_j), 1);
+                paths
+                    .entry((*node_i, *node_j))
+                    .or_default()
+                    .push(vec![*dir]);
+            }
+        }
+        for node_k in chars.iter() {
+            for node_i in chars.iter() {
+                for node_j in chars.iter() {
+                    if node_i == node_j || node_i == node_k || node_j == node_k {
+                        continue;
+                    }
+                    let &curr_d = distances.get(&(*node_i, *node_j)).unwrap_or(&i32::MAX);
+                    let new_d = distances
+                        .get(&(*node_i, *node_k))
+                        .and_then(|&d_ik| {
+                            distances.get(&(*node_k, *node_j)).map(|&d_kj| d_ik + d_kj)
+                        })
+                        .unwrap_or(i32::MAX);
+                    match new_d.cmp(&curr_d) {
+                        Ordering::Less => {
+                            // replace current paths since taking k is shorter
+                            distances.insert((*node_i, *node_j), new_d);
+                            let mut new_paths = vec![];
+                            for paths_ik in paths.get(&(*node_i, *node_k)).unwrap() {
+                                for paths_kj in paths.get(&(*node_k, *node_j)).unwrap() {
+                                    let mut new_path: Vec<Dir> =
+                                        Vec::with_capacity(paths_ik.len() + paths_kj.len());
+                                    new_path.extend(paths_ik);
+                                    new_path.extend(paths_kj);
+                                    new_paths.push(new_path);
+                                }
+                            }
+                            paths.insert((*node_i, *node_j), new_paths);
+                        }
+                        Ordering::Equal => {
+                            if new_d == i32::MAX {
+                                continue;
+                            }
+                            // add new paths since taking k is equal
+                            let mut new_paths: Vec<Vec<Dir>> = vec![];
+                            for paths_ik in paths.get(&(*node_i, *node_k)).unwrap() {
+                                for paths_kj in paths.get(&(*node_k, *node_j)).unwrap() {
+                                    let mut new_path: Vec<Dir> =
+                                        Vec::with_capacity(paths_ik.len() + paths_kj.len());
+                                    new_path.extend(paths_ik);
+                                    new_path.extend(paths_kj);
+                                    new_paths.push(new_path);
+                                }
+                            }
+                            paths
+                                .get_mut(&(*node_i, *node_j))
+                                .get_or_insert(&mut vec![])
+                                .extend(new_paths);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+    paths
+}
+
+fn shortest_path_pair(a: PadNum, b: PadNum, level: i32) -> usize {
+    /*
+    Assuming we are currently at char `a` at level `level + 1`,
+    how many buttons does level `1` have to press in order to press b at the current level?
+     */
+    {
+        let cache = SHORTEST_PATH_PAIRS.lock().unwrap();
+        if cache.contains_key(&(a, b, level)) {
+            return *cache.get(&(a, b, level)).unwrap();
+        }
+    }
+    let paths: Vec<Vec<char>> = PAD_PATHS
+        .get(&(a, b))
+        .unwrap() // get from a to b first
+        .iter()
+        .map(|path_ab| {
+            let mut path_ab = path_ab.clone();
+            path_ab.push('A');
+            path_ab
+        }) // then confirm selection
+        .collect();
+
+    let final_path = if level > 1 {
+        paths
+            .iter()
+            .map(|path| shortest_path(path.clone(), level - 1))
+            .min()
+            .unwrap()
+    } else {
+        assert_eq!(level, 1);
+        paths.iter().map(|path| path.len()).min().unwrap()
+    };
+
+    let mut cache = SHORTEST_PATH_PAIRS.lock().unwrap();
+    cache.insert((a, b, level), final_path);
+
+    final_path
+}
+
+fn shortest_path(sequence: Vec<char>, levels: i32) -> usize {
+    std::iter::once('A')
+        .chain(sequence.clone())
+        .tuple_windows()
+        .map(|(a, b)| shortest_path_pair(a, b, levels))
+        .sum()
+}
+
+/// A door code: its keys, ending in `A`, and the number they spell.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Code {
+    pub keys: Vec<char>,
+    pub number: usize,
+}
+
+impl Code {
+    /// Fewest presses on the outermost directional keypad to type the code when `robots`
+    /// robots on directional keypads stand between it and the numeric keypad.
+    pub fn presses(&self, robots: usize) -> usize {
+        shortest_path(self.keys.clone(), robots as i32 + 1)
+    }
+
+    /// The number of presses times the numeric part of the code.
+    pub fn complexity(&self, robots: usize) -> usize {
+        self.presses(robots) * self.number
+    }
+}
+
+fn parse_codes(input: &str) -> Result<Vec<Code>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            if let Some((idx, _)) = line.char_indices().find(|(_, c)| !NUM_CHARS.contains(c)) {
+                return Err(ParseError::at(input, char_at(line, idx), "invalid key"));
+            }
+            let number = line
+                .strip_suffix('A')
+                .ok_or_else(|| ParseError::at(input, line, "code has to end with 'A'"))?;
+            Ok(Code {
+                keys: line.chars().collect(),
+                number: parse_token(input, number)?,
+            })
+        })
+        .collect()
+}
+
+/// Part 1 types the codes through two robots, part 2 through 25.
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Code>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_codes(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.iter().map(|code| code.complexity(2)).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        input.iter().map(|code| code.complexity(25)).sum()
+    }
+}
@@ -0,0 +1,20 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day21::Day21;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day21>(EXAMPLE);
+    assert_eq!(Day21::part1(&input), 126384);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day21>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
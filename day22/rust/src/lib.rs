@@ -0,0 +1,81 @@
+//! Day 22: Monkey Market.
+
+use common::{parse_token, ParseError, Solution};
+use std::collections::{HashMap, VecDeque};
+
+/// The next secret number of a buyer.
+pub fn evolve(number: i64) -> i64 {
+    let number = ((number * 64) ^ number) % 16777216;
+    let number = ((number / 32) ^ number) % 16777216;
+    ((number * 2048) ^ number) % 16777216
+}
+
+/// The secret number after evolving `number` `steps` times.
+pub fn secret_after(number: i64, steps: usize) -> i64 {
+    (0..steps).fold(number, |number, _| evolve(number))
+}
+
+/// Most bananas to get by selling to each buyer at the first price that follows a single
+/// sequence of four price changes, or 0 without buyers.
+pub fn most_bananas(buyers: &[i64]) -> i64 {
+    buyers
+        .iter()
+        .map(|buyer_n| {
+            let mut buying_sequences: HashMap<(i64, i64, i64, i64), i64> = HashMap::new();
+            let mut prev_price: Option<i64> = None;
+            let mut diffs: VecDeque<i64> = VecDeque::new();
+            let mut buyer_n = *buyer_n;
+            for _ in 0..2000 {
+                let price = buyer_n % 10;
+                if let Some(prev_price) = prev_price {
+                    diffs.push_back(price - prev_price);
+                    if diffs.len() > 4 {
+                        let quadtup = (diffs[0], diffs[1], diffs[2], diffs[3]);
+                        buying_sequences.entry(quadtup).or_insert(prev_price);
+                        diffs.pop_front();
+                    }
+                }
+                prev_price = Some(price);
+                buyer_n = evolve(buyer_n);
+            }
+            buying_sequences
+        })
+        .fold(
+            HashMap::new(),
+            |mut acc: HashMap<(i64, i64, i64, i64), i64>,
+             buying_sequences: HashMap<(i64, i64, i64, i64), i64>| {
+                for (key, value) in buying_sequences {
+                    *acc.entry(key).or_insert(0) += value;
+                }
+                acc
+            },
+        )
+        .into_values()
+        .max()
+        .unwrap_or(0)
+}
+
+fn parse_buyers(input: &str) -> Result<Vec<i64>, ParseError> {
+    input.lines().map(|line| parse_token(input, line)).collect()
+}
+
+/// The input is the initial secret number of each buyer.
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_buyers(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        input.iter().map(|&buyer| secret_after(buyer, 2000)).sum()
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        most_bananas(input)
+    }
+}
@@ -0,0 +1,31 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day22::Day22;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+const EXAMPLE2: &str = include_str!("../../example2.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day22::part1(&parse::<Day22>(EXAMPLE)), 37327623);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day22::part2(&parse::<Day22>(EXAMPLE2)), 23);
+}
+
+#[test]
+fn no_buyers() {
+    let buyers = Day22::parse("").unwrap();
+    assert_eq!((Day22::part1(&buyers), Day22::part2(&buyers)), (0, 0));
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day22>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,245 @@
+//! Day 23: LAN Party.
+
+use common::{ParseError, Solution};
+use std::collections::{HashMap, HashSet};
+
+/// Undirected graph of named computers, each referred to by its index.
+#[derive(Debug)]
+pub struct Graph {
+    adjs: HashMap<usize, HashSet<usize>>,
+    nodes: Vec<String>,
+}
+impl Graph {
+    /// Parses one connection `a-b` per line.
+    pub fn from_string(input: &str) -> Result<Self, ParseError> {
+        let edges: Vec<(String, String)> = input
+            .lines()
+            .map(|line| {
+                line.split_once("-")
+                    .map(|(a, b)| (a.to_string(), b.to_string()))
+                    .ok_or_else(|| ParseError::at(input, line, "expected a connection 'a-b'"))
+            })
+            .collect::<Result<_, _>>()?;
+        let nodes: Vec<String> = edges
+            .iter()
+            .flat_map(|(a, b)| [a.clone(), b.clone()])
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let mut adjs: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (a, b) in edges {
+            let a_idx = nodes.iter().position(|n| n == &a).unwrap();
+            let b_idx = nodes.iter().position(|n| n == &b).unwrap();
+            adjs.entry(a_idx).or_default().insert(b_idx);
+            adjs.entry(b_idx).or_default().insert(a_idx);
+        }
+        Ok(Graph { adjs, nodes })
+    }
+
+    fn degree_sort_nodes(&self) -> Self {
+        // sort by degree, highest first
+        let mut sorted_nodes: Vec<_> = self.nodes.iter().enumerate().collect();
+        sorted_nodes.sort_by(|(a_idx, _), (b_idx, _)| {
+            let a_degree = self.adjs[a_idx].len();
+            let b_degree = self.adjs[b_idx].len();
+            a_degree.cmp(&b_degree)
+        });
+        let mut map_idx_old_new: Vec<usize> = vec![0; self.nodes.len()];
+        for (new_idx, (old_idx, _)) in sorted_nodes.iter().enumerate() {
+            map_idx_old_new[*old_idx] = new_idx;
+        }
+        let new_adjs: HashMap<usize, HashSet<usize>> = self
+            .adjs
+            .iter()
+            .map(|(k, vs)| {
+                (
+                    map_idx_old_new[*k],
+                    vs.iter().map(|v| map_idx_old_new[*v]).collect(),
+                )
+            })
+            .collect();
+        let new_nodes = sorted_nodes.iter().map(|&(_, n)| n.clone()).collect();
+        Graph {
+            adjs: new_adjs,
+            nodes: new_nodes,
+        }
+    }
+
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn name(&self, node: usize) -> &str {
+        &self.nodes[node]
+    }
+
+    pub fn index(&self, name: &str) -> Option<usize> {
+        self.nodes.iter().position(|n| n == name)
+    }
+
+    pub fn neighbors(&self, node: usize) -> &HashSet<usize> {
+        &self.adjs[&node]
+    }
+
+    /// Number of sets of three interconnected computers with at least one name `qualifies`.
+    pub fn count_triangles<F: Fn(&str) -> bool>(&self, qualifies: F) -> usize {
+        // Implements Chiba & Nishizeki algorithm (1985)
+        // O(m^(3/2)) time complexity, where m is the number of edges
+        let sorted_graph = self.degree_sort_nodes();
+        let mut triangles = 0usize;
+        for node in 0..sorted_graph.num_nodes() {
+            let neighbors: &HashSet<usize> = &sorted_graph.adjs[&node];
+            for neighbor in neighbors.iter().filter(|&&n| n > node) {
+                for neighbor_neighbor in sorted_graph.adjs[neighbor].iter().filter(|&&n| n > node) {
+                    if neighbors.contains(neighbor_neighbor) {
+                        // triangle, but does it qualify?
+                        if qualifies(&sorted_graph.nodes[node])
+                            || qualifies(&sorted_graph.nodes[*neighbor])
+                            || qualifies(&sorted_graph.nodes[*neighbor_neighbor])
+                        {
+                            triangles += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        // since we ignore previous nodes, we need to divide only by equivalent permutations
+        // e.g. ka co ta is the same as ka ta co, but co ka ta cannot occur
+        triangles / 2
+    }
+
+    /// Names of the computers in a largest set of interconnected computers, sorted.
+    pub fn maximum_clique(&self) -> Vec<String> {
+        let maximal_cliques = bron_kerbosch(
+            self,
+            HashSet::new(),
+            (0..self.num_nodes()).collect(),
+            HashSet::new(),
+        );
+        let maximum_clique = maximal_cliques
+            .iter()
+            .max_by_key(|clique| clique.len())
+            .unwrap();
+        let mut computers: Vec<_> = maximum_clique
+            .iter()
+            .map(|idx| self.nodes[*idx].clone())
+            .collect();
+        computers.sort();
+        computers
+    }
+}
+
+fn part1(graph: &Graph) -> usize {
+    graph.count_triangles(|name| name.starts_with('t'))
+}
+
+fn bron_kerbosch(
+    graph: &Graph,
+    current: HashSet<usize>,
+    mut potential: HashSet<usize>,
+    mut excluded: HashSet<usize>,
+) -> Vec<HashSet<usize>> {
+    // Implements Bron-Kerbosch algorithm (1973)
+    // O(3^(n/3)) time complexity, where n is the number of nodes
+    // It maintains sets R, P, X, where
+    //  R is the current clique,
+    //  P is the potential clique,
+    //  X is the excluded nodes
+    // In each recursive call, as we add some node to R,
+    // the potential cliques are reduced to nodes that are connected to the added node
+    // i.e. by induction every node in P is connected to every node in R
+    if potential.is_empty() && excluded.is_empty() {
+        return vec![current];
+    }
+
+    let mut cliques = Vec::new();
+    for pnode in potential.clone().iter() {
+        let mut new_current = current.clone();
+        new_current.insert(*pnode);
+        let new_potential: HashSet<_> = potential
+            .intersection(&graph.adjs[pnode])
+            .cloned()
+            .collect();
+        let new_excluded: HashSet<_> = excluded.intersection(&graph.adjs[pnode]).cloned().collect();
+        cliques.extend(bron_kerbosch(
+            graph,
+            new_current,
+            new_potential,
+            new_excluded,
+        ));
+        potential.remove(pnode);
+        excluded.insert(*pnode);
+    }
+    cliques
+}
+
+fn part2(graph: &Graph) -> String {
+    graph.maximum_clique().join(",")
+}
+
+/// Part 1 counts the triangles with a `t` computer, part 2 gives the largest clique.
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Graph;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Graph::from_string(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Triangles with a qualifying computer, found by checking every triple of computers.
+    fn brute_force_triangles(graph: &Graph, qualifies: impl Fn(&str) -> bool) -> usize {
+        let n = graph.num_nodes();
+        let connected = |a: usize, b: usize| graph.neighbors(a).contains(&b);
+        (0..n)
+            .flat_map(|a| (a + 1..n).flat_map(move |b| (b + 1..n).map(move |c| (a, b, c))))
+            .filter(|&(a, b, c)| connected(a, b) && connected(b, c) && connected(a, c))
+            .filter(|&(a, b, c)| [a, b, c].iter().any(|&node| qualifies(graph.name(node))))
+            .count()
+    }
+
+    /// Connection lists between up to 18 computers, some of whose names start with `t`.
+    fn connections() -> impl Strategy<Value = String> {
+        let computer = (
+            prop::sample::select(vec!['k', 't', 'c']),
+            prop::char::range('a', 'f'),
+        )
+            .prop_map(|(first, second)| format!("{}{}", first, second));
+        let connection = (computer.clone(), computer)
+            .prop_filter("self-connection", |(a, b)| a != b)
+            .prop_map(|(a, b)| format!("{}-{}\n", a, b));
+        prop::collection::vec(connection, 1..80).prop_map(|lines| lines.concat())
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(2000))]
+
+        #[test]
+        fn triangles_match_brute_force(input in connections()) {
+            let graph = Graph::from_string(&input).unwrap();
+            let historian = |name: &str| name.starts_with('t');
+            prop_assert_eq!(
+                graph.count_triangles(historian),
+                brute_force_triangles(&graph, historian)
+            );
+            prop_assert_eq!(graph.count_triangles(|_| true), brute_force_triangles(&graph, |_| true));
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day23::Day23;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day23>(EXAMPLE);
+    assert_eq!(Day23::part1(&input), 7);
+    assert_eq!(Day23::part2(&input), "co,de,ka,ta");
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day23>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,310 @@
+//! Day 24: Crossed Wires.
+
+use ahash::RandomState;
+use common::input::two_sections;
+use common::{diagnostic, ParseError, Solution};
+use rayon::prelude::*;
+use regex::Regex;
+use std::cmp::PartialEq;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Values of wires by name.
+pub type Wires = HashMap<String, bool, RandomState>;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+impl Op {
+    pub fn apply(&self, x: bool, y: bool) -> bool {
+        match self {
+            Op::And => x && y,
+            Op::Or => x || y,
+            Op::Xor => x ^ y,
+        }
+    }
+}
+/// A gate combining the wires `x` and `y` into the wire `z`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Instruction {
+    pub op: Op,
+    pub x: String,
+    pub y: String,
+    pub z: String,
+}
+
+/// Checks that `name`, a subslice of `input`, names a wire: three lowercase letters or digits,
+/// where the wires starting with `x`, `y` or `z` continue with the index of their bit.
+fn wire<'a>(input: &str, name: &'a str) -> Result<&'a str, ParseError> {
+    let valid = name.len() == 3
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit())
+        && (!name.starts_with(['x', 'y', 'z'])
+            || name[1..].parse::<u32>().is_ok_and(|bit| bit < u64::BITS));
+    if !valid {
+        return Err(ParseError::at(input, name, "invalid wire name"));
+    }
+    Ok(name)
+}
+
+/// Parses the initial wire values and the gates, which have to get a value on every wire they
+/// read.
+pub fn parse_inputs(input: &str) -> Result<(Wires, Vec<Instruction>), ParseError> {
+    let (inputs_str, gates_str) = two_sections(input, "gates after the wires")?;
+    let inputs_regex = Regex::new(r"(.{3}): (\S+)").unwrap();
+    let inputs: Wires = inputs_regex
+        .captures_iter(inputs_str)
+        .par_bridge()
+        .map(|cap| {
+            let name = wire(input, cap.get(1).unwrap().as_str())?.to_string();
+            match &cap[2] {
+                "0" => Ok((name, false)),
+                "1" => Ok((name, true)),
+                value => Err(ParseError::at(input, value, "expected a bit")),
+            }
+        })
+        .collect::<Result<_, _>>()?;
+    let instructions_regex = Regex::new(r"(.{3}) (\S+) (.{3}) -> (.{3})").unwrap();
+    let gates: Vec<[&str; 3]> = instructions_regex
+        .captures_iter(gates_str)
+        .map(|cap| [1, 3, 4].map(|idx| cap.get(idx).unwrap().as_str()))
+        .collect();
+    let instructions: Vec<Instruction> = instructions_regex
+        .captures_iter(gates_str)
+        .par_bridge()
+        .map(|cap| {
+            let op = match &cap[2] {
+                "AND" => Op::And,
+                "OR" => Op::Or,
+                "XOR" => Op::Xor,
+                op => return Err(ParseError::at(input, op, "invalid operation")),
+            };
+            let [x, y, z] = [1, 3, 4].map(|idx| wire(input, cap.get(idx).unwrap().as_str()));
+            Ok(Instruction {
+                op,
+                x: x?.to_string(),
+                y: y?.to_string(),
+                z: z?.to_string(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+    check_driven(input, &inputs, &gates)?;
+    Ok((inputs, instructions))
+}
+
+/// Checks that every wire the `gates`, as `[x, y, z]` subslices of `input`, read gets a value,
+/// which fails if it is neither an input nor the output of a gate, or if the gates feed each
+/// other in a loop.
+fn check_driven(input: &str, inputs: &Wires, gates: &[[&str; 3]]) -> Result<(), ParseError> {
+    let outputs: HashMap<&str, usize> = gates
+        .iter()
+        .enumerate()
+        .map(|(idx, [_, _, z])| (*z, idx))
+        .collect();
+    for [x, y, _] in gates {
+        for name in [x, y] {
+            if !inputs.contains_key(*name) && !outputs.contains_key(name) {
+                return Err(ParseError::at(input, name, "wire has no value"));
+            }
+        }
+    }
+    // resolve gates in passes, like `simulate`, until no more gates become ready
+    let mut known: HashSet<&str> = inputs.keys().map(String::as_str).collect();
+    let mut pending: Vec<&[&str; 3]> = gates.iter().collect();
+    loop {
+        let before = pending.len();
+        pending.retain(|[x, y, z]| {
+            let ready = known.contains(x) && known.contains(y);
+            if ready {
+                known.insert(z);
+            }
+            !ready
+        });
+        match pending.first() {
+            None => return Ok(()),
+            Some([x, y, _]) if pending.len() == before => {
+                let waiting = if known.contains(x) { y } else { x };
+                return Err(ParseError::at(input, waiting, "gates form a loop"));
+            }
+            Some(_) => {}
+        }
+    }
+}
+
+/// Values of all wires once every gate has produced its output. Gates reading a wire that
+/// never gets a value are left out.
+pub fn simulate(inputs: &Wires, instructions: &[Instruction]) -> Wires {
+    let mut registers = inputs.clone();
+    let mut instructions: VecDeque<Instruction> = instructions.iter().cloned().collect();
+    // gates put back in a row without any gate producing a value in between
+    let mut stalled = 0;
+    while stalled < instructions.len() {
+        let instruction = instructions.pop_front().unwrap();
+        match registers
+            .get(&instruction.x)
+            .and_then(|&a_val| registers.get(&instruction.y).map(|&b_val| (a_val, b_val)))
+        {
+            Some((a_val, b_val)) => {
+                registers.insert(instruction.z.clone(), instruction.op.apply(a_val, b_val));
+                stalled = 0;
+            }
+            None => {
+                instructions.push_back(instruction);
+                stalled += 1;
+            }
+        }
+    }
+    registers
+}
+
+/// The number formed by the wires starting with `prefix`, e.g. `z00`, `z01`, ... for `'z'`.
+pub fn number(wires: &Wires, prefix: char) -> u64 {
+    wires
+        .par_iter()
+        .filter(|(k, &v)| k.starts_with(prefix) && v)
+        .map(|(k, _)| 2u64.pow(k[1..].parse::<u32>().unwrap()))
+        .sum()
+}
+
+fn part1(inputs: &Wires, instructions: &[Instruction]) -> u64 {
+    number(&simulate(inputs, instructions), 'z')
+}
+
+fn prob_check_carry_or_xor1(instructions: &[Instruction], name: &str) -> bool {
+    let counts = instructions
+        .par_iter()
+        .filter(|instruct| instruct.x == name || instruct.y == name)
+        .map(|instruct| match instruct.op {
+            Op::And => (1, 0, 0),
+            Op::Xor => (0, 1, 0),
+            Op::Or => (0, 0, 1),
+        })
+        .reduce_with(|acc, x| (acc.0 + x.0, acc.1 + x.1, acc.2 + x.2))
+        .unwrap_or((0, 0, 0));
+    counts == (1, 1, 0)
+}
+
+fn prob_check_or(instructions: &[Instruction], name: &str) -> bool {
+    instructions
+        .par_iter()
+        .find_any(|instruct| instruct.op == Op::Or && (instruct.x == name || instruct.y == name))
+        .is_some()
+}
+
+/// Outputs of gates that break the structure of a ripple-carry adder, sorted.
+pub fn swapped_wires(instructions: &[Instruction]) -> Vec<String> {
+    let mut wrongs: Vec<String> = Vec::with_capacity(8);
+    // the carry out of the most significant bit is the highest output, e.g. z45
+    let last_z = instructions
+        .iter()
+        .map(|instruct| instruct.z.as_str())
+        .filter(|z| z.starts_with('z'))
+        .max()
+        .unwrap_or_default();
+    // check half-adder
+    for instruct in instructions {
+        match (
+            &instruct.op,
+            instruct.x.as_str(),
+            instruct.y.as_str(),
+            instruct.z.as_str(),
+        ) {
+            (Op::Xor, "x00", "y00", z) | (Op::Xor, "y00", "x00", z) => {
+                if z != "z00" {
+                    diagnostic!("XOR(x00, y00) -> {}, not z00", z);
+                    wrongs.push(z.to_string());
+                }
+            }
+            (Op::And, "x00", "y00", z) | (Op::And, "y00", "x00", z) | (Op::Or, _, _, z) => {
+                if z != last_z && !prob_check_carry_or_xor1(instructions, z) {
+                    diagnostic!("{} is not a carry or XOR1", z);
+                    wrongs.push(z.to_string());
+                }
+            }
+            (Op::Xor, x, y, z) => {
+                if x.starts_with('x') && y.starts_with('y')
+                    || x.starts_with('y') && y.starts_with('x')
+                {
+                    if !prob_check_carry_or_xor1(instructions, z) {
+                        diagnostic!("{} is not a carry or XOR1", z);
+                        wrongs.push(z.to_string());
+                    }
+                } else if !z.starts_with('z') {
+                    diagnostic!("XOR({}, {}) -> {}, not z??", x, y, z);
+                    wrongs.push(z.to_string());
+                }
+            }
+            (Op::And, _, _, z) => {
+                if !prob_check_or(instructions, z) {
+                    diagnostic!("{} is not an OR", z);
+                    wrongs.push(z.to_string());
+                }
+            }
+        }
+    }
+    wrongs.sort();
+    wrongs
+}
+
+/// Part 1 reads the number on the `z` wires, part 2 names the swapped outputs of the adder.
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = (Wires, Vec<Instruction>);
+    type Answer1 = u64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_inputs(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        swapped_wires(&input.1).join(",")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(input: &str) -> (usize, usize, String) {
+        let error = parse_inputs(input).unwrap_err();
+        (error.line, error.column, error.message)
+    }
+
+    #[test]
+    fn rejects_bad_wires() {
+        assert_eq!(
+            error("x00: 1\n\nx00 AND zab -> z00\n"),
+            (3, 9, "invalid wire name".to_string())
+        );
+        assert_eq!(
+            error("x00: 1\n\nx00 AND y00 -> z00\n"),
+            (3, 9, "wire has no value".to_string())
+        );
+        assert_eq!(
+            error("x00: 1\n\nx00 AND abc -> def\ndef OR x00 -> abc\n"),
+            (3, 9, "gates form a loop".to_string())
+        );
+    }
+
+    #[test]
+    fn leaves_out_undriven_gates() {
+        let inputs: Wires = [("x00".to_string(), true)].into_iter().collect();
+        let gate = |x: &str, z: &str| Instruction {
+            op: Op::Or,
+            x: x.to_string(),
+            y: "x00".to_string(),
+            z: z.to_string(),
+        };
+        let wires = simulate(&inputs, &[gate("abc", "z01"), gate("x00", "z00")]);
+        assert_eq!(number(&wires, 'z'), 1);
+    }
+}
@@ -0,0 +1,25 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day24::Day24;
+
+const SMALL_EXAMPLE: &str = include_str!("../../example.txt");
+const EXAMPLE: &str = include_str!("../../example2.txt");
+
+#[test]
+fn small_example() {
+    assert_eq!(Day24::part1(&parse::<Day24>(SMALL_EXAMPLE)), 4);
+}
+
+#[test]
+fn example() {
+    assert_eq!(Day24::part1(&parse::<Day24>(EXAMPLE)), 2024);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day24>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,95 @@
+//! Day 25: Code Chronicle.
+
+use common::input::sections;
+use common::{char_at, diagnostic, ParseError, Solution};
+use rayon::prelude::*;
+
+/// Pin heights of a key or a lock.
+pub type Heights = Vec<i32>;
+
+fn validate_schematic(inputs: &str, schematic: &[&str]) -> Result<(), ParseError> {
+    if schematic.len() != 7 {
+        return Err(ParseError::at(
+            inputs,
+            schematic[0],
+            "expected a schematic of 7 rows",
+        ));
+    }
+    for line in schematic {
+        if let Some((idx, _)) = line.char_indices().find(|&(_, c)| c != '.' && c != '#') {
+            return Err(ParseError::at(
+                inputs,
+                char_at(line, idx),
+                "invalid character",
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn parse_inputs(inputs: &str) -> Result<(Vec<Heights>, Vec<Heights>), ParseError> {
+    let schematics: Vec<Vec<&str>> = sections(inputs)
+        .into_iter()
+        .map(|section| section.lines().collect())
+        .collect();
+    schematics
+        .iter()
+        .try_for_each(|schematic| validate_schematic(inputs, schematic))?;
+    let (keys, locks): (Vec<_>, Vec<_>) = schematics
+        .par_iter()
+        .map(|chunk| {
+            let is_key = chunk[0].chars().all(|c| c == '.');
+            let counts = chunk[1..=5]
+                .iter()
+                .map(|line| {
+                    line.chars()
+                        .map(|c| if c == '.' { 0 } else { 1 })
+                        .collect::<Vec<_>>()
+                })
+                .reduce(|acc, x| acc.iter().zip(x.iter()).map(|(a, b)| a + b).collect())
+                .unwrap();
+            (is_key, counts)
+        })
+        .partition(|(is_key, _)| *is_key);
+    let keys = keys.into_iter().map(|(_, counts)| counts).collect();
+    let locks = locks.into_iter().map(|(_, counts)| counts).collect();
+    Ok((keys, locks))
+}
+
+/// Whether the key and the lock overlap in no column.
+pub fn fits(key: &[i32], lock: &[i32]) -> bool {
+    key.iter().zip(lock.iter()).all(|(k, l)| k + l <= 5)
+}
+
+/// Number of key and lock pairs that fit together.
+pub fn fitting_pairs(keys: &[Heights], locks: &[Heights]) -> i32 {
+    keys.par_iter()
+        .map(|key| locks.par_iter().filter(|lock| fits(key, lock)).count() as i32)
+        .sum()
+}
+
+/// The input is the pin heights of the keys and of the locks; there is no part 2.
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (Vec<Heights>, Vec<Heights>);
+    type Answer1 = i32;
+    type Answer2 = &'static str;
+
+    const HAS_PART2: bool = false;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (keys, locks) = parse_inputs(input)?;
+        diagnostic!("Keys: {:?}", keys);
+        diagnostic!("Locks: {:?}", locks);
+        Ok((keys, locks))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        fitting_pairs(&input.0, &input.1)
+    }
+
+    fn part2(_input: &Self::Input) -> Self::Answer2 {
+        "Merry Christmas!"
+    }
+}
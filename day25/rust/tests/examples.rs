@@ -0,0 +1,20 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day25::Day25;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day25>(EXAMPLE);
+    assert_eq!(Day25::part1(&input), 3);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day25>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1],
+    );
+}
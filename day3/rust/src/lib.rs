@@ -0,0 +1,531 @@
+//! Day 3: Mull It Over.
+
+use common::{ParseError, Solution};
+use std::io::{self, Read};
+use std::ops::RangeInclusive;
+
+/// State of an evaluation that instructions can read and change.
+#[derive(Debug, Clone)]
+pub struct Context {
+    total: i64,
+    /// Whether each enclosing scope is enabled, from the outermost one.
+    scopes: Vec<bool>,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context {
+            total: 0,
+            scopes: vec![true],
+        }
+    }
+}
+
+impl Context {
+    pub fn total(&self) -> i64 {
+        self.total
+    }
+
+    /// Whether the current scope and all scopes enclosing it are enabled.
+    pub fn enabled(&self) -> bool {
+        self.scopes.iter().all(|&enabled| enabled)
+    }
+
+    /// Adds `value` to the total, unless disabled.
+    pub fn add(&mut self, value: i64) {
+        if self.enabled() {
+            self.total += value;
+        }
+    }
+
+    /// Enables or disables the current scope.
+    pub fn set_enabled(&mut self, enabled: bool) {
+        *self.scopes.last_mut().unwrap() = enabled;
+    }
+
+    /// Opens a scope inside the current one.
+    pub fn enter(&mut self, enabled: bool) {
+        self.scopes.push(enabled);
+    }
+
+    /// Closes the current scope, returning whether there was one to close; the outermost scope
+    /// is never closed.
+    pub fn exit(&mut self) -> bool {
+        self.scopes.len() > 1 && self.scopes.pop().is_some()
+    }
+}
+
+type Eval = Box<dyn Fn(&[u32], &mut Context)>;
+
+struct Definition {
+    name: String,
+    /// The name followed by the opening parenthesis.
+    pattern: Vec<u8>,
+    arity: RangeInclusive<usize>,
+    eval: Eval,
+}
+
+/// The instructions the memory is scanned for, written `name(a,b,...)` with operands of 1 to 3
+/// digits, each with its own evaluation.
+#[derive(Default)]
+pub struct InstructionSet {
+    instructions: Vec<Definition>,
+}
+
+impl InstructionSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `mul`, `do` and `don't` of the puzzle.
+    pub fn standard() -> Self {
+        let mut set = InstructionSet::new();
+        set.register("mul", 2..=2, mul)
+            .register("do", 0..=0, |_, context| context.set_enabled(true))
+            .register("don't", 0..=0, |_, context| context.set_enabled(false));
+        set
+    }
+
+    /// Adds the instruction `name`, taking a number of operands within `arity`, which `eval`
+    /// runs. Panics if `name` is already registered or contains parentheses, commas, digits or
+    /// line breaks.
+    pub fn register(
+        &mut self,
+        name: &str,
+        arity: RangeInclusive<usize>,
+        eval: impl Fn(&[u32], &mut Context) + 'static,
+    ) -> &mut Self {
+        assert!(
+            !name.is_empty()
+                && !name
+                    .bytes()
+                    .any(|byte| b"(),\r\n".contains(&byte) || byte.is_ascii_digit()),
+            "invalid instruction name {:?}",
+            name
+        );
+        assert!(
+            self.instructions.iter().all(|other| other.name != name),
+            "instruction {:?} is already registered",
+            name
+        );
+        self.instructions.push(Definition {
+            name: name.to_string(),
+            pattern: format!("{}(", name).into_bytes(),
+            arity,
+            eval: Box::new(eval),
+        });
+        self
+    }
+
+    /// Evaluates all of `memory`.
+    pub fn evaluate(&self, memory: &[u8]) -> Evaluation {
+        let mut interpreter = Interpreter::new(self);
+        interpreter.feed(memory).for_each(drop);
+        interpreter.finish()
+    }
+}
+
+fn mul(operands: &[u32], context: &mut Context) {
+    context.add(operands[0] as i64 * operands[1] as i64);
+}
+
+/// An instruction found in the memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Executed<'s> {
+    /// Offset of the instruction's first byte in the stream, line breaks included.
+    pub offset: usize,
+    pub name: &'s str,
+    pub operands: Vec<u32>,
+    /// Whether the evaluation was enabled when the instruction was reached.
+    pub enabled: bool,
+}
+
+/// A stretch of the memory, in byte offsets, over which the evaluation stayed enabled or
+/// disabled. It ends right after the instruction that switched it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Region {
+    pub start: usize,
+    pub end: usize,
+    pub enabled: bool,
+}
+
+/// Result of evaluating a whole memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Evaluation {
+    pub total: i64,
+    /// Consecutive regions covering the memory.
+    pub regions: Vec<Region>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum State {
+    /// Reading the name of an instruction, which the pending bytes are a prefix of.
+    Name,
+    /// Inside the parentheses of instruction `opcode`, whose last operand has `digits` digits.
+    Operands {
+        opcode: usize,
+        operands: Vec<u32>,
+        digits: usize,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    Continue,
+    Done(usize, Vec<u32>),
+    Fail,
+}
+
+/// Evaluates the memory one byte at a time, so that it can be read in chunks of any size and
+/// instructions may be split between them.
+///
+/// Line breaks are skipped, as the memory is a single stream only wrapped over several lines.
+pub struct Interpreter<'s> {
+    set: &'s InstructionSet,
+    context: Context,
+    state: State,
+    /// Offsets and bytes of the instruction being read.
+    pending: Vec<(usize, u8)>,
+    /// Offset of the next byte.
+    offset: usize,
+    regions: Vec<Region>,
+    region_start: usize,
+}
+
+impl<'s> Interpreter<'s> {
+    pub fn new(set: &'s InstructionSet) -> Self {
+        Interpreter {
+            set,
+            context: Context::default(),
+            state: State::Name,
+            pending: Vec::new(),
+            offset: 0,
+            regions: Vec::new(),
+            region_start: 0,
+        }
+    }
+
+    pub fn total(&self) -> i64 {
+        self.context.total()
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.context.enabled()
+    }
+
+    /// Reads the next byte, returning the instruction it completes, if any.
+    pub fn push(&mut self, byte: u8) -> Option<Executed<'s>> {
+        let offset = self.offset;
+        self.offset += 1;
+        if byte == b'\n' || byte == b'\r' {
+            return None;
+        }
+        self.step(offset, byte)
+    }
+
+    /// Reads a chunk of the memory, yielding the instructions completed in it.
+    pub fn feed<'a>(&'a mut self, chunk: &'a [u8]) -> impl Iterator<Item = Executed<'s>> + 'a {
+        chunk.iter().filter_map(|&byte| self.push(byte))
+    }
+
+    /// Ends the evaluation, closing the last region.
+    pub fn finish(mut self) -> Evaluation {
+        if self.offset > self.region_start {
+            self.regions.push(Region {
+                start: self.region_start,
+                end: self.offset,
+                enabled: self.context.enabled(),
+            });
+        }
+        Evaluation {
+            total: self.context.total(),
+            regions: self.regions,
+        }
+    }
+
+    fn step(&mut self, offset: usize, byte: u8) -> Option<Executed<'s>> {
+        self.pending.push((offset, byte));
+        match self.advance(byte) {
+            Step::Continue => None,
+            Step::Done(opcode, operands) => {
+                let start = self.pending[0].0;
+                self.pending.clear();
+                Some(self.execute(opcode, operands, start, offset + 1))
+            }
+            Step::Fail => {
+                // an instruction may start anywhere after the first byte of the broken one; as
+                // `)` ends every instruction, only the last byte can complete one
+                let replay = std::mem::take(&mut self.pending);
+                replay[1..].iter().fold(None, |done, &(offset, byte)| {
+                    done.or(self.step(offset, byte))
+                })
+            }
+        }
+    }
+
+    /// Moves the state machine on by `byte`, going back to [`State::Name`] once an instruction
+    /// is complete or broken.
+    fn advance(&mut self, byte: u8) -> Step {
+        let step = match &mut self.state {
+            State::Name => {
+                let pending = &self.pending;
+                let matches = |definition: &Definition| {
+                    definition.pattern.len() >= pending.len()
+                        && definition
+                            .pattern
+                            .iter()
+                            .zip(pending)
+                            .all(|(expected, (_, byte))| expected == byte)
+                };
+                let instructions = &self.set.instructions;
+                match instructions.iter().position(|definition| {
+                    definition.pattern.len() == pending.len() && matches(definition)
+                }) {
+                    Some(opcode) => {
+                        self.state = State::Operands {
+                            opcode,
+                            operands: Vec::new(),
+                            digits: 0,
+                        };
+                        Step::Continue
+                    }
+                    None if instructions.iter().any(matches) => Step::Continue,
+                    None => Step::Fail,
+                }
+            }
+            State::Operands {
+                opcode,
+                operands,
+                digits,
+            } => match byte {
+                b'0'..=b'9' if *digits < 3 => {
+                    if *digits == 0 {
+                        operands.push(0);
+                    }
+                    let operand = operands.last_mut().unwrap();
+                    *operand = *operand * 10 + (byte - b'0') as u32;
+                    *digits += 1;
+                    Step::Continue
+                }
+                b',' if *digits > 0 => {
+                    *digits = 0;
+                    Step::Continue
+                }
+                b')' if (*digits > 0 || operands.is_empty())
+                    && self.set.instructions[*opcode]
+                        .arity
+                        .contains(&operands.len()) =>
+                {
+                    Step::Done(*opcode, std::mem::take(operands))
+                }
+                _ => Step::Fail,
+            },
+        };
+        if step != Step::Continue {
+            self.state = State::Name;
+        }
+        step
+    }
+
+    fn execute(
+        &mut self,
+        opcode: usize,
+        operands: Vec<u32>,
+        start: usize,
+        end: usize,
+    ) -> Executed<'s> {
+        let set = self.set;
+        let definition = &set.instructions[opcode];
+        let enabled = self.context.enabled();
+        (definition.eval)(&operands, &mut self.context);
+        if self.context.enabled() != enabled {
+            self.regions.push(Region {
+                start: self.region_start,
+                end,
+                enabled,
+            });
+            self.region_start = end;
+        }
+        Executed {
+            offset: start,
+            name: &definition.name,
+            operands,
+            enabled,
+        }
+    }
+}
+
+/// Evaluates all of the memory in `reader` with `set`, reading it in fixed-size chunks.
+pub fn interpret(set: &InstructionSet, mut reader: impl Read) -> io::Result<Evaluation> {
+    let mut interpreter = Interpreter::new(set);
+    let mut buffer = [0; 4096];
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => return Ok(interpreter.finish()),
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        interpreter.feed(&buffer[..read]).for_each(drop);
+    }
+}
+
+/// Part 1 adds up every `mul`, part 2 only those enabled by `do()` and `don't()`.
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = String;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        let mut set = InstructionSet::new();
+        set.register("mul", 2..=2, mul);
+        set.evaluate(input.as_bytes()).total
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        InstructionSet::standard().evaluate(input.as_bytes()).total
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    fn run(set: &InstructionSet, memory: &str) -> Vec<(usize, String, Vec<u32>)> {
+        Interpreter::new(set)
+            .feed(memory.as_bytes())
+            .map(|executed| {
+                (
+                    executed.offset,
+                    executed.name.to_string(),
+                    executed.operands,
+                )
+            })
+            .collect()
+    }
+
+    fn call(offset: usize, name: &str, operands: &[u32]) -> (usize, String, Vec<u32>) {
+        (offset, name.to_string(), operands.to_vec())
+    }
+
+    #[test]
+    fn reports_offsets() {
+        let set = InstructionSet::standard();
+        let executed: Vec<Executed> = Interpreter::new(&set).feed(EXAMPLE.as_bytes()).collect();
+        let enabled: Vec<bool> = executed.iter().map(|executed| executed.enabled).collect();
+        assert_eq!(
+            run(&set, EXAMPLE),
+            vec![
+                call(1, "mul", &[2, 4]),
+                call(20, "don't", &[]),
+                call(28, "mul", &[5, 5]),
+                call(48, "mul", &[11, 8]),
+                call(59, "do", &[]),
+                call(64, "mul", &[8, 5]),
+            ]
+        );
+        assert_eq!(enabled, vec![true, true, false, false, false, true]);
+    }
+
+    #[test]
+    fn reads_any_chunks() {
+        let set = InstructionSet::standard();
+        let whole: Vec<Executed> = Interpreter::new(&set).feed(EXAMPLE.as_bytes()).collect();
+        for size in 1..EXAMPLE.len() {
+            let mut interpreter = Interpreter::new(&set);
+            let chunked: Vec<Executed> = EXAMPLE
+                .as_bytes()
+                .chunks(size)
+                .flat_map(|chunk| interpreter.feed(chunk).collect::<Vec<_>>())
+                .collect();
+            assert_eq!(chunked, whole);
+        }
+        assert_eq!(
+            interpret(&set, EXAMPLE.as_bytes()).unwrap(),
+            set.evaluate(EXAMPLE.as_bytes())
+        );
+        // line breaks are skipped, but still count towards the offsets
+        assert_eq!(
+            run(&set, "mu\nl(1\r\n2,3)do(\n)"),
+            vec![call(0, "mul", &[12, 3]), call(12, "do", &[])]
+        );
+    }
+
+    #[test]
+    fn limits_operands() {
+        assert_eq!(
+            run(
+                &InstructionSet::standard(),
+                "mul(1234,5)mul(12,3456)mul(999,1)mul(,2)mul(3,)mul(1,2,3)do(1)"
+            ),
+            vec![call(23, "mul", &[999, 1])]
+        );
+    }
+
+    #[test]
+    fn restarts_on_broken_instructions() {
+        let mut set = InstructionSet::standard();
+        set.register("ado", 1..=1, |_, _| {});
+        assert_eq!(
+            run(&set, "mmul(1,2)mul(mul(3,4)do(don't()dodo()ado()"),
+            vec![
+                call(1, "mul", &[1, 2]),
+                call(13, "mul", &[3, 4]),
+                call(24, "don't", &[]),
+                call(33, "do", &[]),
+                call(38, "do", &[]),
+            ]
+        );
+    }
+
+    #[test]
+    fn evaluates_custom_instructions() {
+        let mut set = InstructionSet::standard();
+        set.register("add", 2..=2, |operands, context| {
+            context.add(operands[0] as i64 + operands[1] as i64)
+        })
+        .register("sub", 2..=2, |operands, context| {
+            context.add(operands[0] as i64 - operands[1] as i64)
+        })
+        .register("sum", 0..=usize::MAX, |operands, context| {
+            context.add(operands.iter().map(|&operand| operand as i64).sum())
+        })
+        .register("enter", 1..=1, |operands, context| {
+            context.enter(operands[0] != 0)
+        })
+        .register("exit", 0..=0, |_, context| {
+            context.exit();
+        });
+        let memory = concat!(
+            "add(1,2)sub(10,3)sum(1,2,3,4)sum()sub(1,5)",
+            "enter(0)mul(2,2)exit()mul(3,3)",
+            // a scope enabled inside a disabled one stays disabled
+            "don't()enter(1)mul(5,5)exit()do()",
+        );
+        let evaluation = set.evaluate(memory.as_bytes());
+        assert_eq!(evaluation.total, 25);
+        let region = |start, end, enabled| Region {
+            start,
+            end,
+            enabled,
+        };
+        assert_eq!(
+            evaluation.regions,
+            vec![
+                region(0, 50, true),
+                region(50, 64, false),
+                region(64, 79, true),
+                region(79, 105, false),
+            ]
+        );
+    }
+}
@@ -0,0 +1,25 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day3::Day3;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+const EXAMPLE2: &str = include_str!("../../example2.txt");
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day3::part1(&parse::<Day3>(EXAMPLE)), 161);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day3::part2(&parse::<Day3>(EXAMPLE2)), 48);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day3>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../instructions.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,143 @@
+//! 2D correlation and convolution of integer grids with a kernel, without padding.
+//!
+//! The output has one cell per position where the kernel lies entirely within the input, so it
+//! is smaller than the input by the kernel's size minus one in each dimension.
+
+use grid::Grid;
+
+/// Sum of each input cell under the kernel times the kernel cell above it, for each position of
+/// the kernel's top left corner; what neural network libraries call a convolution.
+pub fn correlate(input: &Grid<i64>, kernel: &Grid<i64>) -> Grid<i64> {
+    if input.height < kernel.height || input.width < kernel.width {
+        return Grid::new(Vec::new());
+    }
+    let rows = (0..=input.height - kernel.height)
+        .map(|top| {
+            (0..=input.width - kernel.width)
+                .map(|left| {
+                    kernel
+                        .iter()
+                        .map(|((row, col), weight)| weight * input[(top + row, left + col)])
+                        .sum()
+                })
+                .collect()
+        })
+        .collect();
+    Grid::new(rows)
+}
+
+/// Convolution in the mathematical sense: [`correlate`] with the kernel turned by half a turn.
+pub fn convolve(input: &Grid<i64>, kernel: &Grid<i64>) -> Grid<i64> {
+    correlate(input, &kernel.rotate_clockwise().rotate_clockwise())
+}
+
+/// The same operations computed by candle, on `f32` tensors.
+#[cfg(feature = "candle")]
+pub mod candle {
+    use candle_core::Device::Cpu;
+    use candle_core::{Result, Tensor};
+    use grid::Grid;
+
+    fn tensor(grid: &Grid<i64>) -> Result<Tensor> {
+        let values: Vec<f32> = grid.iter().map(|(_, &value)| value as f32).collect();
+        Tensor::from_vec(values, (1, 1, grid.height, grid.width), &Cpu)
+    }
+
+    /// [`super::correlate`], exact as long as all values and sums fit into an `f32` mantissa.
+    pub fn correlate(input: &Grid<i64>, kernel: &Grid<i64>) -> Result<Grid<i64>> {
+        if input.height < kernel.height || input.width < kernel.width {
+            return Ok(Grid::new(Vec::new()));
+        }
+        let output = tensor(input)?
+            .conv2d(&tensor(kernel)?, 0, 1, 1, 1)?
+            .squeeze(0)?
+            .squeeze(0)?
+            .to_vec2::<f32>()?;
+        Ok(Grid::new(
+            output
+                .into_iter()
+                .map(|row| row.into_iter().map(|value| value.round() as i64).collect())
+                .collect(),
+        ))
+    }
+
+    /// [`super::convolve`], with the same precision as [`correlate`].
+    pub fn convolve(input: &Grid<i64>, kernel: &Grid<i64>) -> Result<Grid<i64>> {
+        correlate(input, &kernel.rotate_clockwise().rotate_clockwise())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[&[i64]]) -> Grid<i64> {
+        Grid::new(rows.iter().map(|row| row.to_vec()).collect())
+    }
+
+    /// Grids of every size up to 6×6 with fixed pseudo-random values in -50..50.
+    fn grids(seed: u32) -> impl Iterator<Item = Grid<i64>> {
+        let mut state = seed;
+        (1..=6).flat_map(move |height| {
+            (1..=6)
+                .map(|width| {
+                    Grid::new(
+                        (0..height)
+                            .map(|_| {
+                                (0..width)
+                                    .map(|_| {
+                                        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+                                        ((state >> 16) % 100) as i64 - 50
+                                    })
+                                    .collect()
+                            })
+                            .collect(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+    }
+
+    #[test]
+    fn correlates() {
+        let input = grid(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]]);
+        let kernel = grid(&[&[1, 0], &[0, -1]]);
+        assert_eq!(correlate(&input, &kernel), grid(&[&[-4, -4], &[-4, -4]]));
+        assert_eq!(convolve(&input, &kernel), grid(&[&[4, 4], &[4, 4]]));
+        let kernel = grid(&[&[1, 10, 100]]);
+        assert_eq!(correlate(&input, &kernel), grid(&[&[321], &[654], &[987]]));
+        assert_eq!(correlate(&kernel, &input).height, 0);
+    }
+
+    #[test]
+    fn keeps_input_under_unit_kernel() {
+        let unit = grid(&[&[0, 0, 0], &[0, 1, 0], &[0, 0, 0]]);
+        for input in grids(1).filter(|input| input.height >= 3 && input.width >= 3) {
+            let inner = Grid::new(
+                (1..input.height - 1)
+                    .map(|row| input.row(row)[1..input.width - 1].to_vec())
+                    .collect(),
+            );
+            assert_eq!(correlate(&input, &unit), inner);
+            assert_eq!(convolve(&input, &unit), inner);
+        }
+    }
+
+    #[cfg(feature = "candle")]
+    #[test]
+    fn backends_agree() {
+        let inputs: Vec<Grid<i64>> = grids(1).collect();
+        for kernel in grids(2).filter(|kernel| kernel.height <= 3 && kernel.width <= 3) {
+            for input in &inputs {
+                assert_eq!(
+                    candle::correlate(input, &kernel).unwrap(),
+                    correlate(input, &kernel)
+                );
+                assert_eq!(
+                    candle::convolve(input, &kernel).unwrap(),
+                    convolve(input, &kernel)
+                );
+            }
+        }
+    }
+}
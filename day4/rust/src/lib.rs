@@ -0,0 +1,296 @@
+//! Day 4: Ceres Search.
+
+pub mod conv;
+
+use common::{ParseError, Solution};
+use grid::{Coords, Direction8, Grid, Vector};
+
+/// A word found by a [`WordSearch`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match<'w> {
+    pub word: &'w str,
+    /// Cell of the word's first letter.
+    pub start: Coords,
+    /// Direction in which the word is spelled from `start`.
+    pub direction: Direction8,
+}
+
+/// Finds words in a grid of letters, spelled in any of the eight directions.
+#[derive(Debug, Clone, Copy)]
+pub struct WordSearch<'g> {
+    grid: &'g Grid<char>,
+    wrap: bool,
+}
+
+impl<'g> WordSearch<'g> {
+    pub fn new(grid: &'g Grid<char>) -> Self {
+        WordSearch { grid, wrap: false }
+    }
+
+    /// Lets words continue on the opposite edge when they leave the grid, so a long enough word
+    /// may pass the same cell more than once.
+    pub fn wrapping(self, wrap: bool) -> Self {
+        WordSearch { wrap, ..self }
+    }
+
+    /// The cell after `pos` in steps of `step`.
+    fn step(&self, pos: Coords, step: Vector) -> Option<Coords> {
+        if !self.wrap {
+            return self.grid.offset(pos, step);
+        }
+        let (height, width) = (self.grid.height as isize, self.grid.width as isize);
+        Some((
+            (pos.0 as isize + step.y).rem_euclid(height) as usize,
+            (pos.1 as isize + step.x).rem_euclid(width) as usize,
+        ))
+    }
+
+    fn spells(&self, word: &str, start: Coords, direction: Direction8) -> bool {
+        let mut pos = Some(start);
+        word.chars().all(|letter| match pos {
+            Some(current) if self.grid[current] == letter => {
+                pos = self.step(current, direction.vector());
+                true
+            }
+            _ => false,
+        })
+    }
+
+    /// Every occurrence of each of `words`, by start cell row by row, then in the order of
+    /// `words`, then clockwise from [`Direction8::N`]. Empty words are never found, while a
+    /// single letter is found once per direction.
+    pub fn find<'w>(&self, words: &[&'w str]) -> Vec<Match<'w>> {
+        let mut matches = Vec::new();
+        for start in self.grid.coords() {
+            for &word in words {
+                if !word.starts_with(self.grid[start]) {
+                    continue;
+                }
+                for direction in Direction8::ALL {
+                    if self.spells(word, start, direction) {
+                        matches.push(Match {
+                            word,
+                            start,
+                            direction,
+                        });
+                    }
+                }
+            }
+        }
+        matches
+    }
+}
+
+/// Where a [`Pattern`] was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hit {
+    /// Cell under the top left corner of the matching variant.
+    pub top_left: Coords,
+    /// Index of the matching variant in [`Pattern::variants`].
+    pub variant: usize,
+}
+
+/// A rectangular shape of letters to find in a grid, in any rotation or mirror image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    variants: Vec<Grid<Option<char>>>,
+}
+
+impl Pattern {
+    /// The wildcard of templates, matching any letter.
+    pub const WILDCARD: char = '.';
+
+    /// Pattern from a template with one line per row, e.g. `"M.S\n.A.\nM.S"` for an X-MAS.
+    pub fn parse(template: &str) -> Result<Self, ParseError> {
+        let shape = Grid::parse_with(template, |c| Some((c != Self::WILDCARD).then_some(c)))?;
+        let mut variants: Vec<Grid<Option<char>>> = Vec::new();
+        for mut variant in [shape.clone(), shape.transpose()] {
+            for _ in 0..4 {
+                if !variants.contains(&variant) {
+                    variants.push(variant.clone());
+                }
+                variant = variant.rotate_clockwise();
+            }
+        }
+        Ok(Pattern { variants })
+    }
+
+    /// The distinct rotations and mirror images of the template, starting with the template as
+    /// it is written.
+    pub fn variants(&self) -> &[Grid<Option<char>>] {
+        &self.variants
+    }
+
+    fn matches_at(variant: &Grid<Option<char>>, grid: &Grid<char>, top_left: Coords) -> bool {
+        variant.iter().all(|((row, col), expected)| {
+            expected.is_none_or(|letter| grid[(top_left.0 + row, top_left.1 + col)] == letter)
+        })
+    }
+
+    /// Every place where a variant lies entirely within `grid`, by top left corner row by row,
+    /// then in the order of [`Pattern::variants`].
+    pub fn find(&self, grid: &Grid<char>) -> Vec<Hit> {
+        grid.coords()
+            .flat_map(|top_left| {
+                self.variants
+                    .iter()
+                    .enumerate()
+                    .filter(move |(_, variant)| {
+                        top_left.0 + variant.height <= grid.height
+                            && top_left.1 + variant.width <= grid.width
+                            && Self::matches_at(variant, grid, top_left)
+                    })
+                    .map(move |(variant, _)| Hit { top_left, variant })
+            })
+            .collect()
+    }
+
+    pub fn count(&self, grid: &Grid<char>) -> usize {
+        self.find(grid).len()
+    }
+}
+
+/// [`conv::correlate`] on the backend chosen with the `candle` feature.
+fn correlate(input: &Grid<i64>, kernel: &Grid<i64>) -> Grid<i64> {
+    #[cfg(feature = "candle")]
+    return conv::candle::correlate(input, kernel).expect("candle failed to correlate");
+    #[cfg(not(feature = "candle"))]
+    conv::correlate(input, kernel)
+}
+
+fn part2(puzzle: &Grid<char>) -> u32 {
+    let letters = puzzle.map(|&c| match c {
+        'M' => 1,
+        'A' => 2,
+        'S' => 3,
+        _ => 0,
+    });
+    // Every cell of an X gets its own power of 4, so the letters of a 3×3 window can be read off
+    // its sum as base 4 digits.
+    let kernel = Grid::new(vec![vec![1, 0, 4], vec![0, 16, 0], vec![64, 0, 256]]);
+    // the four ways to write two MAS crossing on their A
+    let targets = [997, 487, 877, 367];
+    correlate(&letters, &kernel)
+        .iter()
+        .filter(|(_, sum)| targets.contains(sum))
+        .count() as u32
+}
+
+fn parse_puzzle(input: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input)
+}
+
+/// Part 1 counts `XMAS` in any direction, part 2 the crossed `MAS` patterns.
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Grid<char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_puzzle(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        WordSearch::new(input).find(&["XMAS"]).len() as u32
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_words_in_all_directions() {
+        let grid = Grid::parse("CAT\nOXA\nWOC").unwrap();
+        let search = WordSearch::new(&grid);
+        let found: Vec<(&str, Coords, Direction8)> = search
+            .find(&["CAT", "COW", "CA", "", "DOG"])
+            .into_iter()
+            .map(|found| (found.word, found.start, found.direction))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("CAT", (0, 0), Direction8::E),
+                ("COW", (0, 0), Direction8::S),
+                ("CA", (0, 0), Direction8::E),
+                ("CAT", (2, 2), Direction8::N),
+                ("COW", (2, 2), Direction8::W),
+                ("CA", (2, 2), Direction8::N),
+            ]
+        );
+    }
+
+    #[test]
+    fn wraps_around() {
+        let grid = Grid::parse("ATC\nXXX\nXXX").unwrap();
+        assert!(WordSearch::new(&grid).find(&["CAT"]).is_empty());
+        let found = WordSearch::new(&grid).wrapping(true).find(&["CAT", "TACT"]);
+        assert_eq!(
+            found,
+            vec![
+                Match {
+                    word: "TACT",
+                    start: (0, 1),
+                    direction: Direction8::W,
+                },
+                Match {
+                    word: "CAT",
+                    start: (0, 2),
+                    direction: Direction8::E,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn expands_patterns() {
+        let x_mas = Pattern::parse("M.S\n.A.\nM.S").unwrap();
+        assert_eq!(x_mas.variants().len(), 4);
+        assert_eq!(Pattern::parse("A.\n.A").unwrap().variants().len(), 2);
+        assert_eq!(Pattern::parse("AB").unwrap().variants().len(), 4);
+        assert_eq!(Pattern::parse("AB\nCD").unwrap().variants().len(), 8);
+        assert!(Pattern::parse("AB\nC").is_err());
+    }
+
+    #[test]
+    fn finds_patterns() {
+        let grid = Grid::parse(include_str!("../../test.txt")).unwrap();
+        let x_mas = Pattern::parse("M.S\n.A.\nM.S").unwrap();
+        let hits = x_mas.find(&grid);
+        assert_eq!(hits.len(), 9);
+        assert_eq!(
+            hits[0],
+            Hit {
+                top_left: (0, 1),
+                variant: 0,
+            }
+        );
+        let corner = Pattern::parse("XM\nM.").unwrap();
+        let grid = Grid::parse("XMX\nMXM\nXMX").unwrap();
+        assert_eq!(
+            corner
+                .find(&grid)
+                .into_iter()
+                .map(|hit| hit.top_left)
+                .collect::<Vec<_>>(),
+            // the corner and its opposite, rotated by half a turn, both fit each window
+            vec![
+                (0, 0),
+                (0, 0),
+                (0, 1),
+                (0, 1),
+                (1, 0),
+                (1, 0),
+                (1, 1),
+                (1, 1)
+            ]
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day4::Day4;
+
+const EXAMPLE: &str = include_str!("../../test.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day4>(EXAMPLE);
+    assert_eq!(Day4::part1(&input), 18);
+    assert_eq!(Day4::part2(&input), 9);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day4>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../puzzle.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,591 @@
+//! Day 5: Print Queue.
+
+use common::input::two_sections;
+use common::{diagnostic, parse_token, ParseError, Solution};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+type Updates = Vec<Vec<u32>>;
+
+/// Why the pages of an update cannot be put into a single order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrderError {
+    /// The update lists the page more than once.
+    DuplicatePage(u32),
+    /// The rules between the update's pages form a cycle, each page having to come before the
+    /// next one and the last one before the first, so no order satisfies them all.
+    Impossible { cycle: Vec<u32> },
+    /// The rules allow more than one order, as nothing orders the two `pages`. `order` satisfies
+    /// the rules with the fewest moves from the update, see [`Report::moves`], keeping pages in
+    /// update order wherever that leaves a choice.
+    Ambiguous { order: Vec<u32>, pages: (u32, u32) },
+}
+
+impl Display for OrderError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OrderError::DuplicatePage(page) => write!(f, "page {} is listed twice", page),
+            OrderError::Impossible { cycle } => {
+                write!(f, "impossible order, the rules form the cycle ")?;
+                for page in cycle {
+                    write!(f, "{} -> ", page)?;
+                }
+                write!(f, "{}", cycle[0])
+            }
+            OrderError::Ambiguous { pages, .. } => write!(
+                f,
+                "ambiguous order, no rule orders pages {} and {}",
+                pages.0, pages.1
+            ),
+        }
+    }
+}
+
+impl Error for OrderError {}
+
+/// A rule an update breaks by printing page `after` before page `before`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub before: u32,
+    pub after: u32,
+    /// Position of `before` in the update, counted from 0.
+    pub before_position: usize,
+    /// Position of `after` in the update, which is the smaller one.
+    pub after_position: usize,
+}
+
+/// Audit of an update, see [`RuleSet::report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub violations: Vec<Violation>,
+    /// Result of [`RuleSet::order`] on the update.
+    pub order: Result<Vec<u32>, OrderError>,
+    /// Fewest pages to take out of the update and put back elsewhere to get the corrected
+    /// order, if there is one. This is the fewest moves to satisfy the rules, also when they
+    /// allow several orders, as [`OrderError::Ambiguous`] reports an order needing the fewest.
+    pub moves: Option<usize>,
+}
+
+impl Report {
+    pub fn is_ordered(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// The order satisfying all rules, picked as in [`OrderError::Ambiguous`] if there are
+    /// several.
+    pub fn corrected(&self) -> Option<&[u32]> {
+        match &self.order {
+            Ok(order) | Err(OrderError::Ambiguous { order, .. }) => Some(order),
+            Err(_) => None,
+        }
+    }
+}
+
+/// Number of pages to move to turn `update` into `order`, a permutation of it: all but the
+/// longest run of pages, not necessarily adjacent, that already are in the order.
+fn moves(update: &[u32], order: &[u32]) -> usize {
+    let positions: HashMap<u32, usize> = order
+        .iter()
+        .enumerate()
+        .map(|(position, &page)| (page, position))
+        .collect();
+    // smallest last position of increasing subsequences of each length, by patience sorting
+    let mut tails: Vec<usize> = Vec::new();
+    for page in update {
+        let position = positions[page];
+        let length = tails.partition_point(|&tail| tail < position);
+        if length == tails.len() {
+            tails.push(position);
+        } else {
+            tails[length] = position;
+        }
+    }
+    update.len() - tails.len()
+}
+
+/// Looks for an augmenting path from position `earlier` in Kuhn's bipartite matching of
+/// `inverted` pairs, where `matched` holds the earlier position each later one is matched to.
+fn augment(
+    earlier: usize,
+    inverted: &impl Fn(usize, usize) -> bool,
+    matched: &mut [Option<usize>],
+    seen: &mut [bool],
+) -> bool {
+    for later in 0..matched.len() {
+        if !inverted(earlier, later) || std::mem::replace(&mut seen[later], true) {
+            continue;
+        }
+        if matched[later].is_none_or(|other| augment(other, inverted, matched, seen)) {
+            matched[later] = Some(earlier);
+            return true;
+        }
+    }
+    false
+}
+
+/// Page ordering rules: `X|Y` means that an update with both pages has to print X before Y.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleSet {
+    /// Pages that each page has to come before.
+    successors: BTreeMap<u32, BTreeSet<u32>>,
+}
+
+impl RuleSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the rule `before|after`, returning whether it is new.
+    pub fn insert(&mut self, before: u32, after: u32) -> bool {
+        self.successors.entry(before).or_default().insert(after)
+    }
+
+    /// Whether there is a rule `before|after`.
+    pub fn requires(&self, before: u32, after: u32) -> bool {
+        self.successors
+            .get(&before)
+            .is_some_and(|successors| successors.contains(&after))
+    }
+
+    /// Number of distinct rules.
+    pub fn len(&self) -> usize {
+        self.successors.values().map(BTreeSet::len).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.successors.is_empty()
+    }
+
+    /// A cycle of rules, if there is any. Puzzle inputs have cycles as a whole and only order
+    /// the pages of each update consistently, which [`RuleSet::cycle_within`] checks.
+    pub fn cycle(&self) -> Option<Vec<u32>> {
+        let pages: HashSet<u32> = self
+            .successors
+            .iter()
+            .flat_map(|(&page, successors)| std::iter::once(page).chain(successors.iter().copied()))
+            .collect();
+        self.find_cycle(&pages)
+    }
+
+    /// A cycle of rules between `pages` only, if there is any.
+    pub fn cycle_within(&self, pages: &[u32]) -> Option<Vec<u32>> {
+        self.find_cycle(&pages.iter().copied().collect())
+    }
+
+    fn find_cycle(&self, pages: &HashSet<u32>) -> Option<Vec<u32>> {
+        // whether each visited page is still on the path of the depth-first search
+        let mut on_path: HashMap<u32, bool> = HashMap::new();
+        let mut path = Vec::new();
+        let mut starts: Vec<u32> = pages.iter().copied().collect();
+        starts.sort_unstable();
+        starts.into_iter().find_map(|start| {
+            if on_path.contains_key(&start) {
+                return None;
+            }
+            self.visit(start, pages, &mut on_path, &mut path)
+        })
+    }
+
+    fn visit(
+        &self,
+        page: u32,
+        pages: &HashSet<u32>,
+        on_path: &mut HashMap<u32, bool>,
+        path: &mut Vec<u32>,
+    ) -> Option<Vec<u32>> {
+        on_path.insert(page, true);
+        path.push(page);
+        let successors = self.successors.get(&page).into_iter().flatten();
+        for &next in successors.filter(|next| pages.contains(next)) {
+            match on_path.get(&next) {
+                Some(true) => {
+                    let start = path.iter().position(|&page| page == next).unwrap();
+                    return Some(path[start..].to_vec());
+                }
+                Some(false) => {}
+                None => {
+                    if let Some(cycle) = self.visit(next, pages, on_path, path) {
+                        return Some(cycle);
+                    }
+                }
+            }
+        }
+        path.pop();
+        on_path.insert(page, false);
+        None
+    }
+
+    /// Whether no rule requires a page of `update` to come before an earlier one.
+    pub fn is_ordered(&self, update: &[u32]) -> bool {
+        update.iter().enumerate().all(|(idx, &page)| {
+            update[..idx]
+                .iter()
+                .all(|&earlier| !self.requires(page, earlier))
+        })
+    }
+
+    /// The only order of the pages of `update` that satisfies all rules between them.
+    pub fn order(&self, update: &[u32]) -> Result<Vec<u32>, OrderError> {
+        let mut listed = HashSet::new();
+        if let Some(&page) = update.iter().find(|&&page| !listed.insert(page)) {
+            return Err(OrderError::DuplicatePage(page));
+        }
+        // Kahn's algorithm, taking the earliest page in the update whenever there is a choice
+        let mut predecessors: Vec<usize> = update
+            .iter()
+            .map(|&page| {
+                update
+                    .iter()
+                    .filter(|&&other| self.requires(other, page))
+                    .count()
+            })
+            .collect();
+        let mut placed = vec![false; update.len()];
+        let mut order = Vec::with_capacity(update.len());
+        let mut ambiguous = None;
+        while order.len() < update.len() {
+            let mut free = (0..update.len()).filter(|&idx| !placed[idx] && predecessors[idx] == 0);
+            let Some(next) = free.next() else {
+                let cycle = self
+                    .cycle_within(update)
+                    .expect("pages left without a cycle");
+                return Err(OrderError::Impossible { cycle });
+            };
+            if let (None, Some(other)) = (ambiguous, free.next()) {
+                ambiguous = Some((update[next], update[other]));
+            }
+            placed[next] = true;
+            order.push(update[next]);
+            for (idx, &page) in update.iter().enumerate() {
+                if self.requires(update[next], page) {
+                    predecessors[idx] -= 1;
+                }
+            }
+        }
+        match ambiguous {
+            Some(pages) => Err(OrderError::Ambiguous {
+                order: self.closest_order(update),
+                pages,
+            }),
+            None => Ok(order),
+        }
+    }
+
+    /// An order of the pages of `update`, which have no cycle of rules, that keeps as many of
+    /// them as possible where they are relative to each other.
+    fn closest_order(&self, update: &[u32]) -> Vec<u32> {
+        let len = update.len();
+        // whether the rules require the page at each position before the one at another,
+        // directly or through other pages of the update
+        let mut before: Vec<Vec<bool>> = update
+            .iter()
+            .map(|&page| {
+                update
+                    .iter()
+                    .map(|&other| self.requires(page, other))
+                    .collect()
+            })
+            .collect();
+        for via in 0..len {
+            let through = before[via].clone();
+            for row in before.iter_mut().filter(|row| row[via]) {
+                for (reached, &next) in row.iter_mut().zip(&through) {
+                    *reached |= next;
+                }
+            }
+        }
+        // The pages that stay are those without a pair the update prints the wrong way round.
+        // Such inverted pairs form a partial order on positions, so by Dilworth's theorem the
+        // most pages that can stay is what a maximum matching of inverted pairs leaves over,
+        // and König's theorem finds them from that matching.
+        let inverted = |earlier: usize, later: usize| earlier < later && before[later][earlier];
+        let mut earlier_of: Vec<Option<usize>> = vec![None; len];
+        for earlier in 0..len {
+            let mut seen = vec![false; len];
+            augment(earlier, &inverted, &mut earlier_of, &mut seen);
+        }
+        let mut later_of = vec![None; len];
+        for (later, earlier) in earlier_of.iter().enumerate() {
+            if let Some(earlier) = *earlier {
+                later_of[earlier] = Some(later);
+            }
+        }
+        // positions reachable by alternating paths from unmatched earlier pages, on the earlier
+        // and on the later side of the matching
+        let mut reached_earlier = vec![false; len];
+        let mut reached_later = vec![false; len];
+        let mut stack: Vec<usize> = (0..len).filter(|&idx| later_of[idx].is_none()).collect();
+        while let Some(earlier) = stack.pop() {
+            if std::mem::replace(&mut reached_earlier[earlier], true) {
+                continue;
+            }
+            for later in (0..len).filter(|&later| inverted(earlier, later)) {
+                reached_later[later] = true;
+                if let Some(next) = earlier_of[later] {
+                    stack.push(next);
+                }
+            }
+        }
+        let stays: Vec<usize> = (0..len)
+            .filter(|&idx| reached_earlier[idx] && !reached_later[idx])
+            .collect();
+        // the staying pages keep their order, which the rules never contradict as no two of
+        // them are inverted
+        for pair in stays.windows(2) {
+            before[pair[0]][pair[1]] = true;
+        }
+        let mut predecessors: Vec<usize> = (0..len)
+            .map(|idx| (0..len).filter(|&other| before[other][idx]).count())
+            .collect();
+        let mut placed = vec![false; len];
+        let mut order = Vec::with_capacity(len);
+        while order.len() < len {
+            let next = (0..len)
+                .find(|&idx| !placed[idx] && predecessors[idx] == 0)
+                .expect("staying pages agree with the rules");
+            placed[next] = true;
+            order.push(update[next]);
+            for idx in 0..len {
+                if before[next][idx] {
+                    predecessors[idx] -= 1;
+                }
+            }
+        }
+        order
+    }
+
+    /// Every rule `update` breaks, by the position of the page printed too early, then of the
+    /// page that should have come first.
+    pub fn violations(&self, update: &[u32]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+        for (after_position, &after) in update.iter().enumerate() {
+            for (offset, &before) in update[after_position + 1..].iter().enumerate() {
+                if self.requires(before, after) {
+                    violations.push(Violation {
+                        before,
+                        after,
+                        before_position: after_position + 1 + offset,
+                        after_position,
+                    });
+                }
+            }
+        }
+        violations
+    }
+
+    /// What is wrong with `update` and how to fix it.
+    pub fn report(&self, update: &[u32]) -> Report {
+        let order = self.order(update);
+        let moves = match &order {
+            Ok(order) | Err(OrderError::Ambiguous { order, .. }) => Some(moves(update, order)),
+            Err(_) => None,
+        };
+        Report {
+            violations: self.violations(update),
+            order,
+            moves,
+        }
+    }
+}
+
+impl FromIterator<(u32, u32)> for RuleSet {
+    fn from_iter<T: IntoIterator<Item = (u32, u32)>>(rules: T) -> Self {
+        let mut set = RuleSet::new();
+        for (before, after) in rules {
+            set.insert(before, after);
+        }
+        set
+    }
+}
+
+fn middle(update: &[u32]) -> u32 {
+    update[update.len() / 2]
+}
+
+fn part1(rules: &RuleSet, updates: &[Vec<u32>]) -> u32 {
+    updates
+        .iter()
+        .filter(|update| rules.is_ordered(update))
+        .map(|update| middle(update))
+        .sum()
+}
+
+fn part2(rules: &RuleSet, updates: &[Vec<u32>]) -> u32 {
+    updates
+        .iter()
+        .enumerate()
+        .filter(|(_, update)| !rules.is_ordered(update))
+        .map(|(idx, update)| match rules.order(update) {
+            Ok(order) => middle(&order),
+            Err(e) => {
+                diagnostic!("update {}: {}", idx + 1, e);
+                match e {
+                    OrderError::Ambiguous { order, .. } => middle(&order),
+                    _ => 0,
+                }
+            }
+        })
+        .sum()
+}
+
+fn parse_inputs(input: &str) -> Result<(RuleSet, Updates), ParseError> {
+    let (rules_str, updates_str) = two_sections(input, "updates after the rules")?;
+    let rules = rules_str
+        .lines()
+        .map(|line| {
+            let (before, after) = line
+                .split_once("|")
+                .ok_or_else(|| ParseError::at(input, line, "expected rule 'X|Y'"))?;
+            Ok((parse_token(input, before)?, parse_token(input, after)?))
+        })
+        .collect::<Result<_, _>>()?;
+    let updates = updates_str
+        .lines()
+        .map(|line| {
+            line.split(",")
+                .map(|num| parse_token(input, num))
+                .collect::<Result<_, _>>()
+        })
+        .collect::<Result<_, _>>()?;
+    Ok((rules, updates))
+}
+
+/// Part 1 sums the middle pages of ordered updates, part 2 those of the corrected ones.
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = (RuleSet, Updates);
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_inputs(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        part1(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        part2(&input.0, &input.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> (RuleSet, Updates) {
+        common::testing::parse::<Day5>(include_str!("../../example.txt"))
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let (rules, _) = example();
+        assert_eq!(rules.len(), 21);
+        assert_eq!(rules.cycle(), None);
+        let (real, updates) =
+            common::parse_input::<Day5>(include_str!("../../inputs.txt")).unwrap();
+        assert!(real.cycle().is_some());
+        assert!(updates
+            .iter()
+            .all(|update| real.cycle_within(update).is_none()));
+        let rules: RuleSet = [(1, 2), (2, 3), (3, 1), (3, 4), (5, 5)]
+            .into_iter()
+            .collect();
+        assert_eq!(rules.cycle(), Some(vec![1, 2, 3]));
+        assert_eq!(rules.cycle_within(&[4, 3, 2]), None);
+        assert_eq!(rules.cycle_within(&[4, 5]), Some(vec![5]));
+    }
+
+    #[test]
+    fn distinguishes_ambiguous_from_impossible() {
+        let (rules, _) = example();
+        assert_eq!(
+            rules.order(&[97, 13, 75, 29, 47]),
+            Ok(vec![97, 75, 47, 29, 13])
+        );
+        // 10 and 20 have no rules at all
+        assert_eq!(
+            rules.order(&[20, 13, 10, 97]),
+            Err(OrderError::Ambiguous {
+                order: vec![20, 10, 97, 13],
+                pages: (20, 10),
+            })
+        );
+        assert_eq!(
+            rules.order(&[47, 75, 47]),
+            Err(OrderError::DuplicatePage(47))
+        );
+        let rules: RuleSet = [(1, 2), (2, 3), (3, 1), (3, 4)].into_iter().collect();
+        let error = rules.order(&[4, 3, 2, 1]).unwrap_err();
+        assert_eq!(
+            error,
+            OrderError::Impossible {
+                cycle: vec![1, 2, 3]
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "impossible order, the rules form the cycle 1 -> 2 -> 3 -> 1"
+        );
+        assert!(!rules.is_ordered(&[4, 3]));
+        assert!(rules.is_ordered(&[5, 3, 4]));
+    }
+
+    #[test]
+    fn reports_violations() {
+        let (rules, updates) = example();
+        let report = rules.report(&updates[0]);
+        assert!(report.is_ordered());
+        assert_eq!(
+            (report.corrected(), report.moves),
+            (Some(&updates[0][..]), Some(0))
+        );
+        // 61,13,29 needs 29 before 13
+        let report = rules.report(&updates[4]);
+        assert_eq!(
+            report.violations,
+            vec![Violation {
+                before: 29,
+                after: 13,
+                before_position: 2,
+                after_position: 1,
+            }]
+        );
+        assert_eq!(
+            (report.corrected(), report.moves),
+            (Some(&[61, 29, 13][..]), Some(1))
+        );
+        // 97,13,75,29,47 needs 13 moved to the end and 47 before 29
+        let report = rules.report(&updates[5]);
+        assert_eq!(report.violations.len(), 4);
+        assert_eq!(report.moves, Some(2));
+        let report = rules.report(&[13, 29, 53, 61, 47, 75, 97]);
+        assert_eq!((report.violations.len(), report.moves), (21, Some(6)));
+        let report = rules.report(&[47, 75, 47]);
+        assert_eq!((report.corrected(), report.moves), (None, None));
+    }
+
+    #[test]
+    fn moves_fewest_pages_for_ambiguous_orders() {
+        // 5 is free and 3 and 2 are not ordered, so only 1 has to move to the front
+        let rules: RuleSet = [(1, 2), (1, 3), (1, 4), (2, 4)].into_iter().collect();
+        let report = rules.report(&[3, 2, 5, 1, 4]);
+        assert_eq!(
+            (report.corrected(), report.moves),
+            (Some(&[1, 3, 2, 5, 4][..]), Some(1))
+        );
+        // rules through 9, which is not in the update, do not apply, and 6, 3 and 4 are printed
+        // the wrong way round pairwise, so two of them have to move
+        let rules: RuleSet = [(1, 9), (9, 2), (4, 3), (3, 6), (5, 6)]
+            .into_iter()
+            .collect();
+        let report = rules.report(&[2, 7, 6, 5, 1, 3, 4]);
+        assert_eq!(report.moves, Some(2));
+        let order = report.corrected().unwrap();
+        assert!(rules.is_ordered(order));
+        assert_eq!(moves(&[2, 7, 6, 5, 1, 3, 4], order), 2);
+    }
+}
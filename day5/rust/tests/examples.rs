@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day5::Day5;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day5>(EXAMPLE);
+    assert_eq!(Day5::part1(&input), 143);
+    assert_eq!(Day5::part2(&input), 123);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day5>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../inputs.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
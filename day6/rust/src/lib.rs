@@ -0,0 +1,136 @@
+//! Day 6: Guard Gallivant.
+
+use common::{ParseError, Solution};
+use grid::{Coords, Direction, Grid};
+use std::collections::hash_map::Entry::Vacant;
+use std::collections::{HashMap, HashSet};
+
+/// Number of distinct tiles the guard visits walking from `start` in direction `initial_state`
+/// until it leaves the map, turning right at obstacles, or `None` if it ends up walking in a loop.
+pub fn full_walk(obstacles: &Grid<bool>, start: Coords, initial_state: Direction) -> Option<usize> {
+    let mut state = initial_state;
+    let mut pos = start;
+    let mut visited: HashMap<Coords, HashSet<Direction>> = HashMap::new();
+    visited.insert(pos, HashSet::from([state]));
+
+    while let Some(next_pos) = obstacles.walk(pos, state) {
+        if obstacles[next_pos] {
+            state = state.turn_right();
+        } else {
+            if let Vacant(e) = visited.entry(next_pos) {
+                e.insert(HashSet::from([state]));
+            } else {
+                if visited.get(&next_pos).unwrap().contains(&state) {
+                    return None;
+                }
+                visited.get_mut(&next_pos).unwrap().insert(state);
+            }
+            pos = next_pos;
+        }
+    }
+    Some(visited.len())
+}
+
+/// Obstacles and the guard's starting position in the lab, where the guard starts facing up.
+#[derive(Debug, Clone)]
+pub struct Lab {
+    obstacles: Grid<bool>,
+    start: Coords,
+}
+
+impl Lab {
+    /// Panics if `start` lies outside the lab or on an obstacle.
+    pub fn new(obstacles: Grid<bool>, start: Coords) -> Self {
+        assert!(
+            start.0 < obstacles.height && start.1 < obstacles.width,
+            "start {:?} out of bounds",
+            start
+        );
+        assert!(!obstacles[start], "the guard cannot start on an obstacle");
+        Lab { obstacles, start }
+    }
+
+    /// Whether each tile holds an obstacle `#`.
+    pub fn obstacles(&self) -> &Grid<bool> {
+        &self.obstacles
+    }
+
+    /// The guard's starting position `^`.
+    pub fn start(&self) -> Coords {
+        self.start
+    }
+}
+
+fn parse_map(map_str: &str) -> Result<Lab, ParseError> {
+    let map = Grid::parse_with(map_str, |c| matches!(c, '#' | '^' | '.').then_some(c))?;
+    let start = map
+        .find(&'^')
+        .ok_or_else(|| ParseError::missing(map_str, "guard '^'"))?;
+    Ok(Lab::new(map.map(|&c| c == '#'), start))
+}
+
+/// Number of distinct tiles the guard visits before leaving the lab, or `None` if it walks in a
+/// loop.
+pub fn visited_tiles(lab: &Lab) -> Option<usize> {
+    full_walk(&lab.obstacles, lab.start, Direction::Up)
+}
+
+/// Number of tiles where a new obstruction would trap the guard in a loop. Only tiles on its
+/// path can, and each one is tried by walking again from where the guard would first run into
+/// it. If the guard walks in a loop already, obstructions are only tried along that loop.
+pub fn loop_obstructions(lab: &Lab) -> usize {
+    let Lab { obstacles, start } = lab;
+    let start = *start;
+    let mut visited: HashSet<Coords> = HashSet::new();
+    let mut state = Direction::Up;
+    let mut pos = start;
+    let mut possible_obstacles: HashSet<Coords> = HashSet::new();
+    let mut turns: HashSet<(Coords, Direction)> = HashSet::new();
+
+    loop {
+        visited.insert(pos);
+        let Some(next_pos) = obstacles.walk(pos, state) else {
+            break;
+        };
+
+        if obstacles[next_pos] {
+            // the guard already walks in a loop without a new obstruction
+            if !turns.insert((pos, state)) {
+                break;
+            }
+            state = state.turn_right();
+        } else {
+            // assume next_pos is obstacle
+            if next_pos != start && !visited.contains(&next_pos) {
+                let mut sub_obstacles = obstacles.clone();
+                sub_obstacles[next_pos] = true;
+                if full_walk(&sub_obstacles, pos, state).is_none() {
+                    possible_obstacles.insert(next_pos);
+                }
+            }
+            pos = next_pos;
+        }
+    }
+    possible_obstacles.len()
+}
+
+/// Part 1 counts the tiles the guard visits, part 2 the obstructions that trap it in a loop.
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Lab;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        visited_tiles(input).map_or_else(|| "none".to_string(), |count| count.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        loop_obstructions(input)
+    }
+}
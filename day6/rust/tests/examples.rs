@@ -0,0 +1,41 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day6::{full_walk, loop_obstructions, visited_tiles, Day6, Lab};
+use grid::{Direction, Grid};
+
+const EXAMPLE: &str = include_str!("../../test_map.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day6>(EXAMPLE);
+    assert_eq!(Day6::part1(&input), "41");
+    assert_eq!(Day6::part2(&input), 6);
+}
+
+#[test]
+fn walks_built_labs() {
+    let input = parse::<Day6>(EXAMPLE);
+    assert_eq!(input.start(), (6, 4));
+    assert_eq!(
+        full_walk(input.obstacles(), input.start(), Direction::Right),
+        Some(6)
+    );
+    // obstacles on all four sides of a square walk keep the guard going round it
+    let mut obstacles = Grid::filled(4, 4, false);
+    for pos in [(0, 1), (1, 3), (3, 2), (2, 0)] {
+        obstacles[pos] = true;
+    }
+    let lab = Lab::new(obstacles, (2, 1));
+    assert_eq!(visited_tiles(&lab), None);
+    assert_eq!(Day6::part1(&lab), "none");
+    assert_eq!(loop_obstructions(&lab), 0);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day6>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../map.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,97 @@
+//! Day 7: Bridge Repair.
+
+use common::{parse_token, ParseError, Solution};
+
+/// A calibration equation: the test value and the numbers that should combine into it.
+#[derive(Debug, Clone)]
+pub struct Equation {
+    pub target: u64,
+    pub values: Vec<u64>,
+}
+
+impl Equation {
+    /// Whether placing `+` or `*` (and `||` if `with_concat`) between the values, evaluated
+    /// left to right, can produce the target.
+    pub fn is_solvable(&self, with_concat: bool) -> bool {
+        is_evaluable(self.clone(), with_concat)
+    }
+}
+
+fn is_evaluable(eq: Equation, with_concat: bool) -> bool {
+    if eq.values.len() == 1 {
+        return eq.target == eq.values[0];
+    }
+    let merges = if with_concat {
+        vec![
+            eq.values[0] + eq.values[1],
+            eq.values[0] * eq.values[1],
+            (eq.values[0].to_string() + &eq.values[1].to_string())
+                .parse()
+                .unwrap(),
+        ]
+    } else {
+        vec![eq.values[0] + eq.values[1], eq.values[0] * eq.values[1]]
+    };
+    merges.iter().any(|merge| {
+        if *merge > eq.target {
+            return false;
+        }
+        let mut new_values = vec![*merge];
+        new_values.extend_from_slice(&eq.values[2..]);
+        is_evaluable(
+            Equation {
+                target: eq.target,
+                values: new_values,
+            },
+            with_concat,
+        )
+    })
+}
+
+/// Total of the targets of the equations that can be solved.
+pub fn calibration_result(equations: &[Equation], with_concat: bool) -> u64 {
+    equations
+        .iter()
+        .filter(|eq| eq.is_solvable(with_concat))
+        .map(|eq| eq.target)
+        .sum()
+}
+
+fn parse_equations(input: &str) -> Result<Vec<Equation>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (target, values) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at(input, line, "expected 'target: values'"))?;
+            Ok(Equation {
+                target: parse_token(input, target)?,
+                values: values
+                    .split(" ")
+                    .map(|num| parse_token(input, num))
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect()
+}
+
+/// Part 1 sums the equations solvable with `+` and `*`, part 2 also allows concatenation.
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Equation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_equations(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        calibration_result(input, false)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        calibration_result(input, true)
+    }
+}
@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day7::Day7;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day7>(EXAMPLE);
+    assert_eq!(Day7::part1(&input), 3749);
+    assert_eq!(Day7::part2(&input), 11387);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day7>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../equations.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,70 @@
+//! Day 8: Resonant Collinearity.
+
+use common::{ParseError, Solution};
+use grid::{Coords, Grid, Point};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+/// Positions of the antennas of each frequency.
+pub type Antennas = HashMap<char, Vec<Coords>>;
+
+/// Number of distinct tiles on the map twice as far from one antenna as from another of the
+/// same frequency.
+pub fn antinodes(map: &Grid<char>, positions: &Antennas) -> usize {
+    positions
+        .values()
+        .flat_map(|pos| {
+            pos.iter().permutations(2).filter_map(|ps| {
+                let [p1, p2] = [ps[0], ps[1]];
+                map.offset(*p2, Point::from(*p2) - Point::from(*p1))
+            })
+        })
+        .unique()
+        .count()
+}
+
+/// Number of distinct tiles on the map in line with at least two antennas of the same
+/// frequency, counting the antennas themselves.
+pub fn resonant_antinodes(map: &Grid<char>, positions: &Antennas) -> usize {
+    let mut antinodes: HashSet<Coords> = HashSet::new();
+    for char_positions in positions.values() {
+        for perm in char_positions.iter().permutations(2) {
+            let (p1, p2) = (*perm[0], *perm[1]);
+            antinodes.extend(map.ray(p2, Point::from(p2) - Point::from(p1)));
+        }
+    }
+    antinodes.len()
+}
+
+fn parse_positions(map: &Grid<char>) -> Antennas {
+    let mut positions: Antennas = HashMap::new();
+    for (pos, &c) in map.iter() {
+        if c != '.' {
+            positions.entry(c).or_default().push(pos);
+        }
+    }
+    positions
+}
+
+/// The input is the map and its antennas; part 1 counts antinodes and part 2 resonant ones.
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = (Grid<char>, Antennas);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let map = Grid::parse(input)?;
+        let positions = parse_positions(&map);
+        Ok((map, positions))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        antinodes(&input.0, &input.1)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        resonant_antinodes(&input.0, &input.1)
+    }
+}
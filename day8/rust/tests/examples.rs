@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day8::Day8;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day8>(EXAMPLE);
+    assert_eq!(Day8::part1(&input), 14);
+    assert_eq!(Day8::part2(&input), 34);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day8>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../map.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,217 @@
+//! Day 9: Disk Fragmenter.
+
+use common::{char_at, ParseError, Solution};
+use itertools::Itertools;
+
+fn block_hash(start_pos: u32, size: u32, id: u32) -> u64 {
+    let start_pos = start_pos as u64;
+    let size = size as u64;
+    let id = id as u64;
+
+    if size == 0 {
+        return 0;
+    }
+    if start_pos > 0 {
+        id * ((start_pos + size - 1) * (start_pos + size) - (start_pos - 1) * start_pos) / 2
+    } else {
+        id * ((start_pos + size - 1) * (start_pos + size)) / 2
+    }
+}
+
+/// Checksum after moving file blocks one at a time from the end of the disk into the
+/// leftmost free block.
+pub fn compact_blocks(disk_map: &[u32]) -> u64 {
+    let mut disk_map = disk_map.to_vec();
+    let mut current_pos = 0;
+    let mut current_idx = 0;
+    let mut hash = 0;
+    loop {
+        if current_idx >= disk_map.len() {
+            break;
+        }
+        let size = disk_map[current_idx];
+
+        if current_idx % 2 == 0 {
+            let id = current_idx as u32 / 2;
+            hash += block_hash(current_pos, size, id);
+            current_pos += size;
+        } else {
+            let mut gap = size;
+            // stop once every file after this gap has been moved into it
+            while gap > 0 && disk_map.len() > current_idx + 1 {
+                let last = disk_map.last().unwrap();
+                let last_id = (disk_map.len() - 1) as u32 / 2;
+                if gap >= *last {
+                    gap -= last;
+                    hash += block_hash(current_pos, *last, last_id);
+                    current_pos += last;
+                    disk_map.pop(); // pop item
+                    disk_map.pop(); // pop gap
+                } else {
+                    hash += block_hash(current_pos, gap, last_id);
+                    current_pos += gap;
+                    *disk_map.last_mut().unwrap() -= gap;
+                    break;
+                }
+            }
+        }
+        current_idx += 1;
+    }
+    hash
+}
+
+/// Checksum after moving each whole file, highest ID first, into the leftmost gap that fits it.
+pub fn compact_files(disk_map: &[u32]) -> u64 {
+    let disk_map = disk_map.to_vec();
+    let mut hash = 0;
+    let mut indices: Vec<u32> = vec![0];
+    let mut gaps: Vec<(u32, u32)> = Vec::new();
+
+    for (id, (block, gap)) in disk_map.iter().tuples().enumerate() {
+        indices.push(indices[id] + block + gap);
+        let prev_gap = gaps.last().unwrap_or(&(0, 0));
+        gaps.push((prev_gap.0 + prev_gap.1 + block, *gap));
+    }
+
+    for cand_id in (0..disk_map.len().div_ceil(2)).rev() {
+        let cand_map_idx = cand_id * 2;
+        let cand_size = disk_map[cand_map_idx];
+        let mut new_cand_idx = indices[cand_id];
+        for (gap_idx, gap) in gaps.iter_mut().enumerate() {
+            if indices[cand_id] < gap.0 {
+                // no gaps to the left
+                break;
+            }
+            if cand_size <= gap.1 {
+                new_cand_idx = gap.0;
+                gap.1 -= cand_size;
+                gap.0 += cand_size;
+                if gap.1 == 0 {
+                    gaps.remove(gap_idx); // theoretically this should be O(1), but rust doesn't have a suitable linked list
+                }
+                break;
+            }
+        }
+        hash += block_hash(new_cand_idx, cand_size, cand_id as u32);
+    }
+    hash
+}
+
+fn parse_disk_map(input: &str) -> Result<Vec<u32>, ParseError> {
+    input
+        .lines()
+        .flat_map(|line| line.char_indices().map(move |(idx, c)| (line, idx, c)))
+        .map(|(line, idx, c)| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::at(input, char_at(line, idx), "invalid digit"))
+        })
+        .collect()
+}
+
+/// The input is the dense disk map, one digit per file or gap.
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<u32>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_disk_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1 {
+        compact_blocks(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Answer2 {
+        compact_files(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// File ids of each block, `None` for free space.
+    fn blocks(disk_map: &[u32]) -> Vec<Option<u32>> {
+        disk_map
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, &size)| {
+                let id = (idx % 2 == 0).then_some(idx as u32 / 2);
+                std::iter::repeat_n(id, size as usize)
+            })
+            .collect()
+    }
+
+    fn checksum(blocks: &[Option<u32>]) -> u64 {
+        blocks
+            .iter()
+            .enumerate()
+            .map(|(pos, id)| pos as u64 * id.unwrap_or(0) as u64)
+            .sum()
+    }
+
+    /// Moves single blocks from the end into the leftmost free block.
+    fn brute_force_part1(disk_map: &[u32]) -> u64 {
+        let mut blocks = blocks(disk_map);
+        let mut free = 0;
+        let mut last = blocks.len();
+        loop {
+            while free < blocks.len() && blocks[free].is_some() {
+                free += 1;
+            }
+            while last > 0 && blocks[last - 1].is_none() {
+                last -= 1;
+            }
+            if last == 0 || free >= last - 1 {
+                return checksum(&blocks);
+            }
+            blocks.swap(free, last - 1);
+        }
+    }
+
+    /// Moves whole files, highest id first, into the leftmost free span that fits them.
+    fn brute_force_part2(disk_map: &[u32]) -> u64 {
+        let mut blocks = blocks(disk_map);
+        for id in (0..disk_map.len().div_ceil(2) as u32).rev() {
+            let start = blocks.iter().position(|&b| b == Some(id)).unwrap();
+            let size = disk_map[id as usize * 2] as usize;
+            let target =
+                (0..start).find(|&pos| blocks[pos..pos + size].iter().all(Option::is_none));
+            if let Some(target) = target {
+                for offset in 0..size {
+                    blocks.swap(start + offset, target + offset);
+                }
+            }
+        }
+        checksum(&blocks)
+    }
+
+    /// Disk maps with files of 1 to 9 blocks, separated by gaps of 0 to 9 blocks.
+    fn disk_maps() -> impl Strategy<Value = Vec<u32>> {
+        (1u32..=9, prop::collection::vec((0u32..=9, 1u32..=9), 0..30)).prop_map(|(first, rest)| {
+            let mut disk_map = vec![first];
+            disk_map.extend(rest.into_iter().flat_map(|(gap, file)| [gap, file]));
+            disk_map
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(4000))]
+
+        #[test]
+        fn block_hash_matches_sum(start_pos in 0u32..100_000, size in 0u32..=9, id in 0u32..10_000) {
+            let expected: u64 = (start_pos..start_pos + size).map(|pos| pos as u64 * id as u64).sum();
+            prop_assert_eq!(block_hash(start_pos, size, id), expected);
+        }
+
+        #[test]
+        fn compaction_matches_brute_force(disk_map in disk_maps()) {
+            prop_assert_eq!(compact_blocks(&disk_map), brute_force_part1(&disk_map));
+            prop_assert_eq!(compact_files(&disk_map), brute_force_part2(&disk_map));
+        }
+    }
+}
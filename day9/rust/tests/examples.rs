@@ -0,0 +1,21 @@
+use common::testing::{check_answers, parse};
+use common::Solution;
+use day9::Day9;
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[test]
+fn example() {
+    let input = parse::<Day9>(EXAMPLE);
+    assert_eq!(Day9::part1(&input), 1928);
+    assert_eq!(Day9::part2(&input), 2858);
+}
+
+#[test]
+fn answers() {
+    check_answers::<Day9>(
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../diskmap.txt"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/../answers.txt"),
+        &[1, 2],
+    );
+}
@@ -0,0 +1,349 @@
+//! Points, vectors and directions on the plane of a grid.
+//!
+//! All types use screen axes: `x` is the column and grows to the right, `y` is the row and
+//! grows downwards, so [`Direction::Up`] is `(0, -1)`. [`crate::Coords`] are `(row, column)`,
+//! i.e. `(y, x)`; convert with [`Point::from`] and [`Point::coords`].
+
+use crate::Coords;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+/// Difference between two [`Point`]s.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, PartialOrd, Ord)]
+pub struct Vector {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    /// `(row, column)` of the point, if neither is negative.
+    pub fn coords(self) -> Option<Coords> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        (other - self).manhattan()
+    }
+
+    /// The four points sharing an edge with this one, clockwise starting above.
+    pub fn neighbors4(self) -> impl Iterator<Item = Point> {
+        Direction::ALL.into_iter().map(move |d| self + d)
+    }
+
+    /// The eight points sharing an edge or a corner with this one, clockwise starting above.
+    pub fn neighbors8(self) -> impl Iterator<Item = Point> {
+        Direction8::ALL.into_iter().map(move |d| self + d)
+    }
+}
+
+impl From<Coords> for Point {
+    fn from((row, col): Coords) -> Self {
+        Point::new(col as isize, row as isize)
+    }
+}
+
+impl Vector {
+    pub const fn new(x: isize, y: isize) -> Self {
+        Vector { x, y }
+    }
+
+    pub fn manhattan(self) -> usize {
+        self.x.unsigned_abs() + self.y.unsigned_abs()
+    }
+
+    /// Quarter turn to the right on screen, i.e. clockwise.
+    pub fn rotate_right(self) -> Self {
+        Vector::new(-self.y, self.x)
+    }
+
+    /// Quarter turn to the left on screen, i.e. counter-clockwise.
+    pub fn rotate_left(self) -> Self {
+        Vector::new(self.y, -self.x)
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Point::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        Point::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Self::Output {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Self::Output {
+        Vector::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Self::Output {
+        Vector::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Self::Output {
+        Vector::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        Vector::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+/// One of the four directions along the axes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting with [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Vector::new(0, -1),
+            Direction::Right => Vector::new(1, 0),
+            Direction::Down => Vector::new(0, 1),
+            Direction::Left => Vector::new(-1, 0),
+        }
+    }
+
+    /// Clockwise quarter turn.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Counter-clockwise quarter turn.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Right => Direction::Up,
+            Direction::Down => Direction::Right,
+            Direction::Left => Direction::Down,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// Whether the direction moves along the x axis.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// Parses one of the arrows `^`, `>`, `v` and `<`.
+    pub fn from_arrow(c: char) -> Option<Self> {
+        match c {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + rhs.vector()
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, rhs: Direction) {
+        *self = *self + rhs;
+    }
+}
+
+/// One of the eight compass directions, including the diagonals.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// Clockwise, starting with [`Direction8::N`].
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction8::N => Vector::new(0, -1),
+            Direction8::NE => Vector::new(1, -1),
+            Direction8::E => Vector::new(1, 0),
+            Direction8::SE => Vector::new(1, 1),
+            Direction8::S => Vector::new(0, 1),
+            Direction8::SW => Vector::new(-1, 1),
+            Direction8::W => Vector::new(-1, 0),
+            Direction8::NW => Vector::new(-1, -1),
+        }
+    }
+
+    /// Clockwise turn by `eighths` eighths of a full turn.
+    pub fn turn_right(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn_right(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Right => Direction8::E,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+impl Add<Direction8> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self + rhs.vector()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axes() {
+        let origin = Point::new(0, 0);
+        assert_eq!(origin + Direction::Up, Point::new(0, -1));
+        assert_eq!(origin + Direction::Right, Point::new(1, 0));
+        assert_eq!(Point::from((2, 5)), Point::new(5, 2));
+        assert_eq!(Point::new(5, 2).coords(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 2).coords(), None);
+    }
+
+    #[test]
+    fn turns() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_right().turn_left(), d);
+            assert_eq!(d.turn_right().turn_right(), d.opposite());
+            assert_eq!(d.vector().rotate_right(), d.turn_right().vector());
+            assert_eq!(d.vector().rotate_left(), d.turn_left().vector());
+            assert_eq!(-d.vector(), d.opposite().vector());
+            assert_eq!(Direction::from_arrow(d.arrow()), Some(d));
+            assert_eq!(Direction8::from(d).vector(), d.vector());
+        }
+        for d in Direction8::ALL {
+            assert_eq!(d.opposite().vector(), -d.vector());
+            assert_eq!(d.is_diagonal(), d.vector().manhattan() == 2);
+        }
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point::new(1, -2), Point::new(-3, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a + (b - a), b);
+        assert_eq!(a.neighbors8().count(), 8);
+        assert!(a.neighbors4().all(|n| n.manhattan(a) == 1));
+    }
+}
@@ -0,0 +1,406 @@
+pub mod geometry;
+
+pub use geometry::{Direction, Direction8, Point, Vector};
+
+use common::{char_at, ParseError};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// `(row, column)` of a cell, with rows growing downwards.
+pub type Coords = (usize, usize);
+
+/// Rectangular grid of cells, stored row by row.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    pub height: usize,
+    pub width: usize,
+}
+
+impl<T> Index<Coords> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Coords) -> &Self::Output {
+        assert!(index.1 < self.width, "column {} out of bounds", index.1);
+        &self.data[index.0 * self.width + index.1]
+    }
+}
+
+impl<T> IndexMut<Coords> for Grid<T> {
+    fn index_mut(&mut self, index: Coords) -> &mut T {
+        assert!(index.1 < self.width, "column {} out of bounds", index.1);
+        &mut self.data[index.0 * self.width + index.1]
+    }
+}
+
+/// Panics if the point lies outside the grid.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Point) -> &Self::Output {
+        self.at(index)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", index))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, index: Point) -> &mut T {
+        let coords = self
+            .checked_coords(index)
+            .unwrap_or_else(|| panic!("{:?} out of bounds", index));
+        &mut self[coords]
+    }
+}
+
+impl<T> Grid<T> {
+    /// Grid from its rows, which all need to have the same length.
+    pub fn new(data: Vec<Vec<T>>) -> Self {
+        let height = data.len();
+        let width = data.first().map_or(0, |row| row.len());
+        assert!(
+            data.iter().all(|row| row.len() == width),
+            "rows differ in length"
+        );
+        Grid {
+            data: data.into_iter().flatten().collect(),
+            height,
+            width,
+        }
+    }
+
+    /// Parses one cell per character of each line of `input`, failing on characters
+    /// `cell` rejects, on ragged rows and on empty input.
+    pub fn parse_with<F>(input: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let mut data = Vec::new();
+        let mut height = 0;
+        let mut width = None;
+        for line in input.lines() {
+            let mut row_width = 0;
+            for (idx, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    ParseError::at(input, char_at(line, idx), "invalid character")
+                })?;
+                data.push(value);
+                row_width += 1;
+            }
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::at(input, line, "rows differ in length"));
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                data,
+                height,
+                width,
+            }),
+            _ => Err(ParseError::missing(input, "grid")),
+        }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.checked_coords(point).is_some()
+    }
+
+    /// Coordinates of `point`, if it lies within the grid.
+    pub fn checked_coords(&self, point: Point) -> Option<Coords> {
+        point
+            .coords()
+            .filter(|&(row, col)| row < self.height && col < self.width)
+    }
+
+    /// The cell `offset` away from `pos`, if it lies within the grid.
+    pub fn offset(&self, pos: Coords, offset: Vector) -> Option<Coords> {
+        self.checked_coords(Point::from(pos) + offset)
+    }
+
+    pub fn walk(&self, pos: Coords, direction: Direction) -> Option<Coords> {
+        self.offset(pos, direction.vector())
+    }
+
+    /// The cell at `point`, if it lies within the grid.
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.checked_coords(point).map(|coords| &self[coords])
+    }
+
+    pub fn get(&self, pos: Coords) -> Option<&T> {
+        (pos.0 < self.height && pos.1 < self.width).then(|| &self[pos])
+    }
+
+    pub fn get_mut(&mut self, pos: Coords) -> Option<&mut T> {
+        (pos.0 < self.height && pos.1 < self.width).then(|| &mut self[pos])
+    }
+
+    /// All coordinates, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = Coords> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// All cells with their coordinates, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coords, &T)> {
+        self.coords().zip(self.data.iter())
+    }
+
+    /// The up to four cells sharing an edge with `pos`.
+    pub fn neighbors4(&self, pos: Coords) -> impl Iterator<Item = Coords> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.walk(pos, direction))
+    }
+
+    /// The up to eight cells sharing an edge or a corner with `pos`.
+    pub fn neighbors8(&self, pos: Coords) -> impl Iterator<Item = Coords> + '_ {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |direction| self.offset(pos, direction.vector()))
+    }
+
+    /// Cells from `start` (included) in steps of `step` until leaving the grid.
+    pub fn ray(&self, start: Coords, step: Vector) -> impl Iterator<Item = Coords> + '_ {
+        std::iter::successors(self.get(start).map(|_| start), move |&pos| {
+            self.offset(pos, step)
+        })
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[row * self.width..(row + 1) * self.width]
+    }
+
+    /// The rows from top to bottom, none if the grid has no columns.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // without columns there are no cells, and max(1) only keeps chunks from panicking
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `col` from top to bottom. Panics if the column is out of bounds.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.width, "column {} out of bounds", col);
+        self.data.iter().skip(col).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Diagonals running down and to the right, starting with the one in the bottom left corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (1..self.height)
+            .filter(|_| self.width > 0)
+            .rev()
+            .map(|row| (row, 0))
+            .chain((0..self.width).map(|col| (0, col)));
+        starts.map(|start| self.ray(start, Vector::new(1, 1)).map(|pos| &self[pos]))
+    }
+
+    /// Diagonals running down and to the left, starting with the one in the top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let starts = (0..self.width).map(|col| (0, col)).chain(
+            (1..self.height)
+                .filter(|_| self.width > 0)
+                .map(|row| (row, self.width - 1)),
+        );
+        starts.map(|start| self.ray(start, Vector::new(-1, 1)).map(|pos| &self[pos]))
+    }
+
+    /// Coordinates of the first cell, row by row, matching `predicate`.
+    pub fn position<P: FnMut(&T) -> bool>(&self, mut predicate: P) -> Option<Coords> {
+        self.iter()
+            .find_map(|(pos, value)| predicate(value).then_some(pos))
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            data: self.data.iter().map(f).collect(),
+            height: self.height,
+            width: self.width,
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Coordinates of the first cell, row by row, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Coords> {
+        self.position(|v| v == value)
+    }
+
+    /// Coordinates of all cells holding `value`.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coords> + 'a {
+        self.iter()
+            .filter_map(move |(pos, v)| (v == value).then_some(pos))
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(height: usize, width: usize, value: T) -> Self {
+        Grid {
+            data: vec![value; height * width],
+            height,
+            width,
+        }
+    }
+
+    /// Grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Grid {
+            data: (0..self.width)
+                .flat_map(|col| self.column(col).cloned())
+                .collect(),
+            height: self.width,
+            width: self.height,
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let mut rotated = self.transpose();
+        // without cells there are no rows to reverse
+        if rotated.data.is_empty() {
+            return rotated;
+        }
+        rotated
+            .data
+            .chunks_mut(rotated.width)
+            .for_each(|row| row.reverse());
+        rotated
+    }
+
+    pub fn rotate_counter_clockwise(&self) -> Self {
+        let mut rotated = self.transpose();
+        if rotated.data.is_empty() {
+            return rotated;
+        }
+        let width = rotated.width;
+        let rows: Vec<Vec<T>> = rotated
+            .data
+            .chunks(width)
+            .rev()
+            .map(<[T]>::to_vec)
+            .collect();
+        rotated.data = rows.into_iter().flatten().collect();
+        rotated
+    }
+}
+
+impl Grid<char> {
+    /// Grid holding the characters of `input` as they are.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        Self::parse_with(input, Some)
+    }
+}
+
+/// Draws the grid as one line per row, without separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::parse("abc\ndef").unwrap()
+    }
+
+    fn collect<'a>(lines: impl Iterator<Item = impl Iterator<Item = &'a char>>) -> Vec<String> {
+        lines.map(|line| line.collect()).collect()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = grid();
+        assert_eq!((grid.height, grid.width), (2, 3));
+        assert_eq!(grid[(1, 0)], 'd');
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let error = Grid::parse("ab\nabc").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = Grid::parse_with("01\n2x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.token.as_str()),
+            (2, 2, "x")
+        );
+        assert!(Grid::parse("").is_err());
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = grid();
+        let mut four: Vec<Coords> = grid.neighbors4((0, 0)).collect();
+        four.sort();
+        assert_eq!(four, vec![(0, 1), (1, 0)]);
+        assert_eq!(grid.neighbors8((0, 1)).count(), 5);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 5);
+    }
+
+    #[test]
+    fn lines() {
+        let grid = grid();
+        assert_eq!(collect(grid.rows().map(|row| row.iter())), ["abc", "def"]);
+        assert_eq!(collect(grid.columns()), ["ad", "be", "cf"]);
+        assert_eq!(collect(grid.diagonals()), ["d", "ae", "bf", "c"]);
+        assert_eq!(collect(grid.anti_diagonals()), ["a", "bd", "ce", "f"]);
+        let ray: Vec<Coords> = grid.ray((0, 2), Vector::new(-1, 1)).collect();
+        assert_eq!(ray, vec![(0, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn lines_without_columns() {
+        for grid in [Grid::<char>::new(vec![]), Grid::new(vec![vec![], vec![]])] {
+            assert_eq!(grid.rows().count(), 0);
+            assert_eq!(grid.columns().count(), 0);
+            assert_eq!(grid.diagonals().count(), 0);
+            assert_eq!(grid.anti_diagonals().count(), 0);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "column 0 out of bounds")]
+    fn column_out_of_bounds() {
+        Grid::new(vec![Vec::<char>::new()]).column(0).count();
+    }
+
+    #[test]
+    fn rotates_grids_without_cells() {
+        for (height, width) in [(0, 0), (0, 3), (2, 0)] {
+            let grid = Grid::filled(height, width, '.');
+            for rotated in [grid.rotate_clockwise(), grid.rotate_counter_clockwise()] {
+                assert_eq!((rotated.height, rotated.width), (width, height));
+            }
+        }
+    }
+
+    #[test]
+    fn find() {
+        let grid = grid();
+        assert_eq!(grid.find(&'e'), Some((1, 1)));
+        assert_eq!(grid.find(&'z'), None);
+        let grid = Grid::parse("a.\n.a").unwrap();
+        assert_eq!(
+            grid.find_all(&'a').collect::<Vec<_>>(),
+            vec![(0, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = grid();
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad\n");
+        assert_eq!(grid.rotate_clockwise().rotate_counter_clockwise(), grid);
+    }
+}
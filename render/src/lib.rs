@@ -0,0 +1,346 @@
+//! Drawing grid-shaped puzzle states, to the terminal or to image files.
+//!
+//! A [`Canvas`] holds one glyph and colour per cell. Build it from a [`Grid`] or from scratch,
+//! draw overlays such as paths, visited cells or robots on top, then print it to stderr or save
+//! it as a PPM or PNG image. [`Frames`] saves a numbered image per step of a simulation.
+
+use grid::{Grid, Point};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const GREY: Color = Color::rgb(110, 110, 110);
+    pub const WHITE: Color = Color::rgb(230, 230, 230);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(80, 200, 80);
+    pub const BLUE: Color = Color::rgb(60, 120, 220);
+    pub const YELLOW: Color = Color::rgb(230, 200, 40);
+    pub const ORANGE: Color = Color::rgb(230, 130, 30);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b }
+    }
+}
+
+/// How a single cell is drawn: a glyph in the terminal, a solid square in images.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cell {
+    pub glyph: char,
+    pub color: Color,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, color: Color) -> Self {
+        Cell { glyph, color }
+    }
+
+    /// Nothing to see here.
+    pub const EMPTY: Cell = Cell::new('.', Color::BLACK);
+    pub const WALL: Cell = Cell::new('#', Color::GREY);
+    pub const PATH: Cell = Cell::new('O', Color::GREEN);
+    pub const VISITED: Cell = Cell::new('+', Color::BLUE);
+    pub const ROBOT: Cell = Cell::new('@', Color::YELLOW);
+    pub const ANTINODE: Cell = Cell::new('#', Color::RED);
+}
+
+/// Grid of cells to draw.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Canvas {
+    cells: Grid<Cell>,
+}
+
+impl Canvas {
+    pub fn new(height: usize, width: usize) -> Self {
+        Canvas {
+            cells: Grid::filled(height, width, Cell::EMPTY),
+        }
+    }
+
+    /// Canvas with each cell of `grid` drawn as `cell` decides.
+    pub fn from_grid<T, F: FnMut(&T) -> Cell>(grid: &Grid<T>, cell: F) -> Self {
+        Canvas {
+            cells: grid.map(cell),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.width
+    }
+
+    pub fn cell<P: Into<Point>>(&self, pos: P) -> Option<Cell> {
+        self.cells.at(pos.into()).copied()
+    }
+
+    /// Draws `cell` at `pos`, unless it lies outside the canvas.
+    pub fn draw<P: Into<Point>>(&mut self, pos: P, cell: Cell) {
+        if let Some(coords) = self.cells.checked_coords(pos.into()) {
+            self.cells[coords] = cell;
+        }
+    }
+
+    /// Draws `cell` at all `positions`, skipping those outside the canvas.
+    pub fn overlay<P, I>(&mut self, positions: I, cell: Cell) -> &mut Self
+    where
+        P: Into<Point>,
+        I: IntoIterator<Item = P>,
+    {
+        positions.into_iter().for_each(|pos| self.draw(pos, cell));
+        self
+    }
+
+    /// Like [`Canvas::overlay`], but only recolours, keeping the glyphs underneath.
+    pub fn tint<P, I>(&mut self, positions: I, color: Color) -> &mut Self
+    where
+        P: Into<Point>,
+        I: IntoIterator<Item = P>,
+    {
+        for pos in positions {
+            if let Some(coords) = self.cells.checked_coords(pos.into()) {
+                self.cells[coords].color = color;
+            }
+        }
+        self
+    }
+
+    /// The glyphs with 24-bit ANSI colour escapes, one line per row.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.rows() {
+            let mut current = None;
+            for cell in row {
+                if current != Some(cell.color) {
+                    let Color { r, g, b } = cell.color;
+                    // Black would be invisible on most terminals, so draw it dimmed instead.
+                    if cell.color == Color::BLACK {
+                        out.push_str("\x1b[0;2m");
+                    } else {
+                        out.push_str(&format!("\x1b[0;38;2;{};{};{}m", r, g, b));
+                    }
+                    current = Some(cell.color);
+                }
+                out.push(cell.glyph);
+            }
+            out.push_str("\x1b[0m\n");
+        }
+        out
+    }
+
+    /// Writes the canvas to `out`, with or without colours.
+    pub fn write_to<W: Write>(&self, mut out: W, colored: bool) -> std::io::Result<()> {
+        if colored {
+            write!(out, "{}", self.to_ansi())
+        } else {
+            write!(out, "{}", self)
+        }
+    }
+
+    /// Prints the canvas to stderr, which is where debug output goes so stdout only holds
+    /// answers. Coloured if stderr is a terminal and `NO_COLOR` is not set.
+    pub fn eprint(&self) {
+        let stderr = std::io::stderr().lock();
+        let colored = stderr.is_terminal() && std::env::var_os("NO_COLOR").is_none();
+        self.write_to(stderr, colored)
+            .expect("failed to write to stderr");
+    }
+
+    /// RGB bytes of the image, drawing each cell as a `scale` x `scale` square.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.height() * self.width() * scale * scale * 3);
+        for row in self.cells.rows() {
+            for _ in 0..scale {
+                for cell in row {
+                    for _ in 0..scale {
+                        pixels.extend([cell.color.r, cell.color.g, cell.color.b]);
+                    }
+                }
+            }
+        }
+        pixels
+    }
+
+    /// The image as a binary PPM, with each cell `scale` pixels wide.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let mut ppm = format!(
+            "P6\n{} {}\n255\n",
+            self.width() * scale,
+            self.height() * scale
+        )
+        .into_bytes();
+        ppm.extend(self.pixels(scale));
+        ppm
+    }
+
+    /// The image as a PNG, with each cell `scale` pixels wide.
+    pub fn to_png(&self, scale: usize) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut png = Vec::new();
+        let mut encoder = png::Encoder::new(
+            &mut png,
+            (self.width() * scale) as u32,
+            (self.height() * scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()?
+            .write_image_data(&self.pixels(scale))?;
+        Ok(png)
+    }
+
+    /// Saves the image to `path`, as PNG or PPM depending on its extension.
+    pub fn save(&self, path: &Path, scale: usize) -> Result<(), Box<dyn Error>> {
+        let bytes = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("png") => self.to_png(scale)?,
+            Some("ppm") => self.to_ppm(scale),
+            _ => return Err(format!("{}: expected a .png or .ppm file", path.display()).into()),
+        };
+        std::fs::write(path, bytes)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(())
+    }
+}
+
+/// Draws the glyphs without colours.
+impl Display for Canvas {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.cells.map(|cell| cell.glyph))
+    }
+}
+
+/// Image format of saved frames.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Format {
+    Ppm,
+    Png,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+        }
+    }
+}
+
+/// Saves consecutive states of a simulation as `frame_00000.png`, `frame_00001.png`, ...
+pub struct Frames {
+    dir: PathBuf,
+    format: Format,
+    scale: usize,
+    count: usize,
+}
+
+impl Frames {
+    /// Frames in `dir`, which is created if needed.
+    pub fn new(dir: &Path, format: Format, scale: usize) -> Result<Self, Box<dyn Error>> {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        Ok(Frames {
+            dir: dir.to_path_buf(),
+            format,
+            scale,
+            count: 0,
+        })
+    }
+
+    /// Saves the next frame, returning its path.
+    pub fn push(&mut self, canvas: &Canvas) -> Result<PathBuf, Box<dyn Error>> {
+        let path = self.dir.join(format!(
+            "frame_{:05}.{}",
+            self.count,
+            self.format.extension()
+        ));
+        canvas.save(&path, self.scale)?;
+        self.count += 1;
+        Ok(path)
+    }
+
+    /// Number of frames saved so far.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn canvas() -> Canvas {
+        let maze = Grid::parse("#.#\n...").unwrap();
+        let mut canvas = Canvas::from_grid(&maze, |&c| match c {
+            '#' => Cell::WALL,
+            _ => Cell::EMPTY,
+        });
+        canvas.overlay([(1, 0), (1, 1)], Cell::PATH);
+        canvas.overlay([Point::new(2, 1), Point::new(7, 7)], Cell::ROBOT);
+        canvas
+    }
+
+    #[test]
+    fn draws_overlays() {
+        let canvas = canvas();
+        assert_eq!(canvas.to_string(), "#.#\nOO@\n");
+        assert_eq!(canvas.cell((1, 2)), Some(Cell::ROBOT));
+        assert_eq!(canvas.cell(Point::new(-1, 0)), None);
+        let mut tinted = canvas.clone();
+        tinted.tint([(0, 0)], Color::RED);
+        assert_eq!(tinted.cell((0, 0)), Some(Cell::new('#', Color::RED)));
+    }
+
+    #[test]
+    fn colours_terminal_output() {
+        let ansi = canvas().to_ansi();
+        assert!(ansi.contains("\x1b[0;38;2;80;200;80mOO"));
+        assert_eq!(ansi.lines().count(), 2);
+        let mut plain = Vec::new();
+        canvas().write_to(&mut plain, false).unwrap();
+        assert_eq!(plain, b"#.#\nOO@\n");
+    }
+
+    #[test]
+    fn encodes_images() {
+        let canvas = canvas();
+        let ppm = canvas.to_ppm(2);
+        let header = b"P6\n6 4\n255\n";
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+        // Second pixel row, first pixel: still the wall in the top left cell.
+        let offset = header.len() + 6 * 3;
+        assert_eq!(ppm[offset..offset + 3], [110, 110, 110]);
+        let png = canvas.to_png(1).unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+
+    #[test]
+    fn numbers_frames() {
+        let dir = std::env::temp_dir().join(format!("render-frames-{}", std::process::id()));
+        let mut frames = Frames::new(&dir.join("steps"), Format::Ppm, 1).unwrap();
+        assert!(frames.is_empty());
+        let first = frames.push(&canvas()).unwrap();
+        let second = frames.push(&Canvas::new(1, 1)).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(first, dir.join("steps/frame_00000.ppm"));
+        assert_eq!(second, dir.join("steps/frame_00001.ppm"));
+        assert_eq!(std::fs::read(&first).unwrap(), canvas().to_ppm(1));
+        assert_eq!(std::fs::read(&second).unwrap(), Canvas::new(1, 1).to_ppm(1));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,356 @@
+//! Shortest path searches over graphs given as a `neighbors` callback.
+//!
+//! Every search keeps all optimal predecessors of each node it reaches, so besides distances
+//! and a single shortest path, the [`Paths`] it returns can enumerate the nodes on all shortest
+//! paths or count them.
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Edge costs; `Default` has to be zero, and every edge has to cost more than that. With
+/// zero-cost edges, ties could make nodes each other's predecessors, and walking paths back
+/// would never end.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<T: Copy + Ord + Add<Output = T> + Default> Cost for T {}
+
+/// Result of a search from a single start node.
+pub struct Paths<N, C> {
+    start: N,
+    distances: HashMap<N, C>,
+    predecessors: HashMap<N, Vec<N>>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> Paths<N, C> {
+    fn new(start: N) -> Self {
+        Paths {
+            distances: HashMap::from([(start.clone(), C::default())]),
+            predecessors: HashMap::new(),
+            start,
+            goal: None,
+        }
+    }
+
+    /// Records reaching `next` from `node` at `distance`, returning whether it is an improvement.
+    fn relax(&mut self, node: &N, next: N, distance: C) -> bool {
+        match self.distances.get(&next) {
+            Some(&known) if distance > known => false,
+            Some(&known) if distance == known => {
+                let predecessors = self.predecessors.entry(next).or_default();
+                if !predecessors.contains(node) {
+                    predecessors.push(node.clone());
+                }
+                false
+            }
+            _ => {
+                self.distances.insert(next.clone(), distance);
+                self.predecessors.insert(next, vec![node.clone()]);
+                true
+            }
+        }
+    }
+
+    pub fn start(&self) -> &N {
+        &self.start
+    }
+
+    /// The first node matching the goal predicate that the search settled, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    /// Length of the shortest path to `node`, if the search reached it.
+    ///
+    /// Searches that stopped at a goal only know final distances up to the goal's.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    pub fn distances(&self) -> &HashMap<N, C> {
+        &self.distances
+    }
+
+    /// All nodes the search reached, including the start.
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.distances.keys()
+    }
+
+    /// Nodes directly before `node` on its shortest paths.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.predecessors.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// One shortest path from the start to `target`, both included.
+    pub fn path_to(&self, target: &N) -> Option<Vec<N>> {
+        self.distances.get(target)?;
+        let mut path = vec![target.clone()];
+        while let Some(previous) = self.predecessors(path.last().unwrap()).first() {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// All nodes lying on any shortest path from the start to one of `targets`.
+    pub fn nodes_on_paths_to(&self, targets: impl IntoIterator<Item = N>) -> HashSet<N> {
+        let mut nodes = HashSet::new();
+        let mut todo: Vec<N> = targets
+            .into_iter()
+            .filter(|target| self.distances.contains_key(target))
+            .collect();
+        while let Some(node) = todo.pop() {
+            if nodes.insert(node.clone()) {
+                todo.extend(self.predecessors(&node).iter().cloned());
+            }
+        }
+        nodes
+    }
+
+    /// Number of distinct shortest paths from the start to `target`.
+    pub fn count_paths_to(&self, target: &N) -> usize {
+        fn count<N: Clone + Eq + Hash, C: Cost>(
+            paths: &Paths<N, C>,
+            node: &N,
+            cache: &mut HashMap<N, usize>,
+        ) -> usize {
+            if *node == paths.start {
+                return 1;
+            }
+            if let Some(&known) = cache.get(node) {
+                return known;
+            }
+            let total = paths
+                .predecessors(node)
+                .iter()
+                .map(|previous| count(paths, previous, cache))
+                .sum();
+            cache.insert(node.clone(), total);
+            total
+        }
+        if !self.distances.contains_key(target) {
+            return 0;
+        }
+        count(self, target, &mut HashMap::new())
+    }
+}
+
+/// Breadth-first search for graphs with unit edge costs, stopping once a node matching `goal`
+/// is reached. Pass `|_| false` as `goal` to explore everything reachable.
+pub fn bfs<N, I, F, G>(start: N, mut neighbors: F, mut goal: G) -> Paths<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        // Nodes are dequeued layer by layer, so all predecessors of this one are known.
+        if goal(&node) {
+            paths.goal = Some(node);
+            break;
+        }
+        let distance = paths.distances[&node] + 1;
+        for next in neighbors(&node) {
+            if paths.relax(&node, next.clone(), distance) {
+                queue.push_back(next);
+            }
+        }
+    }
+    paths
+}
+
+/// Dijkstra's algorithm, with `neighbors` returning each neighbour with the positive cost to
+/// reach it. Stops once all nodes as close as the first one matching `goal` are settled.
+///
+/// Panics on an edge that costs zero, see [`Cost`].
+pub fn dijkstra<N, C, I, F, G>(start: N, neighbors: F, goal: G) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, neighbors, |_| C::default(), goal)
+}
+
+struct Queued<N, C> {
+    priority: C,
+    distance: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // BinaryHeap is a max-heap.
+        self.priority.cmp(&other.priority).reverse()
+    }
+}
+
+/// A* search guided by `heuristic`, which has to be consistent (never decrease by more than
+/// an edge's cost along it, and be zero at goals) for distances and predecessors to be exact.
+///
+/// Panics on an edge that costs zero, see [`Cost`].
+pub fn astar<N, C, I, F, H, G>(
+    start: N,
+    mut neighbors: F,
+    mut heuristic: H,
+    mut goal: G,
+) -> Paths<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    let mut paths = Paths::new(start.clone());
+    let mut queue = BinaryHeap::from([Queued {
+        priority: heuristic(&start),
+        distance: C::default(),
+        node: start,
+    }]);
+    let mut best: Option<C> = None;
+    while let Some(Queued {
+        priority,
+        distance,
+        node,
+    }) = queue.pop()
+    {
+        if distance > paths.distances[&node] {
+            continue;
+        }
+        // Keep going through ties, they may be further optimal predecessors.
+        if best.is_some_and(|best| priority > best) {
+            break;
+        }
+        if goal(&node) {
+            if paths.goal.is_none() {
+                paths.goal = Some(node.clone());
+                best = Some(distance);
+            }
+            continue;
+        }
+        for (next, cost) in neighbors(&node) {
+            assert!(cost > C::default(), "edge costs have to be positive");
+            let next_distance = distance + cost;
+            if paths.relax(&node, next.clone(), next_distance) {
+                queue.push(Queued {
+                    priority: next_distance + heuristic(&next),
+                    distance: next_distance,
+                    node: next,
+                });
+            }
+        }
+    }
+    paths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Grid graph of `size` x `size` nodes, moving right or down.
+    fn lattice(size: u32) -> impl Fn(&(u32, u32)) -> Vec<(u32, u32)> {
+        move |&(x, y)| {
+            let mut next = Vec::new();
+            if x + 1 < size {
+                next.push((x + 1, y));
+            }
+            if y + 1 < size {
+                next.push((x, y + 1));
+            }
+            next
+        }
+    }
+
+    #[test]
+    fn bfs_counts_all_shortest_paths() {
+        let paths = bfs((0, 0), lattice(4), |_| false);
+        assert_eq!(paths.distance(&(3, 3)), Some(6));
+        // Choose 3 of 6 steps to go right.
+        assert_eq!(paths.count_paths_to(&(3, 3)), 20);
+        assert_eq!(paths.nodes_on_paths_to([(1, 1)]).len(), 4);
+        let path = paths.path_to(&(3, 3)).unwrap();
+        assert_eq!((path.len(), path[0], path[6]), (7, (0, 0), (3, 3)));
+    }
+
+    #[test]
+    fn bfs_stops_at_goal() {
+        let paths = bfs((0, 0), lattice(10), |&node| node == (1, 1));
+        assert_eq!(paths.goal(), Some(&(1, 1)));
+        assert_eq!(paths.count_paths_to(&(1, 1)), 2);
+        assert_eq!(paths.distance(&(9, 9)), None);
+    }
+
+    /// A diamond whose two sides cost the same, and a direct edge that is more expensive.
+    fn diamond(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 2), ('d', 5)],
+            'b' => vec![('d', 3)],
+            'c' => vec![('d', 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_keeps_tied_predecessors() {
+        let paths = dijkstra('a', diamond, |&node| node == 'd');
+        assert_eq!(paths.distance(&'d'), Some(4));
+        let mut predecessors = paths.predecessors(&'d').to_vec();
+        predecessors.sort();
+        assert_eq!(predecessors, vec!['b', 'c']);
+        assert_eq!(paths.nodes_on_paths_to(['d']).len(), 4);
+        assert_eq!(paths.count_paths_to(&'d'), 2);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let weighted = |&(x, y): &(u32, u32)| {
+            lattice(6)(&(x, y))
+                .into_iter()
+                .map(|next| (next, 1 + (next.0 * 7 + next.1 * 3) % 4))
+        };
+        let target = (5, 5);
+        let manhattan = |&(x, y): &(u32, u32)| (target.0 - x) + (target.1 - y);
+        let plain = dijkstra((0, 0), weighted, |&node| node == target);
+        let guided = astar((0, 0), weighted, manhattan, |&node| node == target);
+        assert_eq!(plain.distance(&target), guided.distance(&target));
+        assert_eq!(
+            plain.nodes_on_paths_to([target]),
+            guided.nodes_on_paths_to([target])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "edge costs have to be positive")]
+    fn rejects_zero_costs() {
+        // a and b would be each other's predecessors at distance 0
+        let free = |node: &char| match node {
+            'a' => vec![('b', 0u32), ('c', 1)],
+            'b' => vec![('a', 0)],
+            _ => vec![],
+        };
+        dijkstra('a', free, |&node| node == 'c');
+    }
+}